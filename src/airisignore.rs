@@ -0,0 +1,245 @@
+//! `.airisignore`: paths airis must leave alone
+//!
+//! Uses the familiar gitignore syntax at the workspace root:
+//! - `#` comments and blank lines are ignored
+//! - A pattern without a slash matches a file or directory name at any depth
+//! - A pattern containing a slash (or starting with `/`) matches from the root
+//! - A trailing `/` is accepted for directories
+//! - `!pattern` re-includes a path excluded by an earlier pattern
+//!
+//! Matching a directory also matches everything under it. The last matching
+//! pattern wins, as in git. A directory holding a matched path (`dist/keep.me`)
+//! must not be removed as a whole; see [`AirisIgnore::has_ignored_descendant`].
+
+use anyhow::{Context, Result};
+use glob::{MatchOptions, Pattern};
+use std::fs;
+use std::path::{Component, Path};
+use walkdir::WalkDir;
+
+/// File name read from the workspace root
+pub const AIRISIGNORE_FILE: &str = ".airisignore";
+
+/// `*` and `?` never cross a `/`, as in gitignore
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+#[derive(Debug)]
+struct Rule {
+    pattern: Pattern,
+    anchored: bool,
+    negated: bool,
+    /// Leading components of an anchored pattern before the first wildcard
+    literal_prefix: Vec<String>,
+}
+
+/// Parsed `.airisignore` rules
+#[derive(Debug, Default)]
+pub struct AirisIgnore {
+    rules: Vec<Rule>,
+}
+
+impl AirisIgnore {
+    /// Load `.airisignore` from `root`. A missing file yields an empty matcher.
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(AIRISIGNORE_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&content)
+    }
+
+    /// Parse `.airisignore` content
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut rules = Vec::new();
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (negated, line) = match line.strip_prefix('!') {
+                Some(rest) => (true, rest),
+                None => (false, line),
+            };
+            let line = line.trim_end_matches('/');
+            let anchored = line.contains('/');
+            let line = line.trim_start_matches('/');
+            if line.is_empty() {
+                continue;
+            }
+            let pattern = Pattern::new(line)
+                .with_context(|| format!("Invalid pattern in {}: {}", AIRISIGNORE_FILE, line))?;
+            let literal_prefix = line
+                .split('/')
+                .take_while(|part| !part.contains(['*', '?', '[']))
+                .map(str::to_string)
+                .collect();
+            rules.push(Rule {
+                pattern,
+                anchored,
+                negated,
+                literal_prefix,
+            });
+        }
+        Ok(Self { rules })
+    }
+
+    /// Whether `.airisignore` had no rules
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Whether `path` (relative to the workspace root) is matched
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        let components = normal_components(path);
+
+        let mut ignored = false;
+        for rule in &self.rules {
+            if rule_matches(rule, &components) {
+                ignored = !rule.negated;
+            }
+        }
+        ignored
+    }
+
+    /// Whether anything under the directory `path` (relative to `root`) is
+    /// matched. Deleting `path` as a whole would take those entries with it.
+    pub fn has_ignored_descendant(&self, root: &Path, path: &Path) -> bool {
+        // Only walk the tree when some rule could match below `path`
+        let components = normal_components(path);
+        if !self
+            .rules
+            .iter()
+            .any(|rule| !rule.negated && could_match_under(rule, &components))
+        {
+            return false;
+        }
+        WalkDir::new(root.join(path))
+            .min_depth(1)
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .any(|entry| {
+                entry
+                    .path()
+                    .strip_prefix(root)
+                    .is_ok_and(|rel| self.is_ignored(rel))
+            })
+    }
+}
+
+fn normal_components(path: &Path) -> Vec<String> {
+    path.components()
+        .filter_map(|c| match c {
+            Component::Normal(s) => Some(s.to_string_lossy().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether `rule` could match something below the directory `components`:
+/// basename patterns match at any depth, anchored ones only where their
+/// literal prefix and the directory lie on the same branch.
+fn could_match_under(rule: &Rule, components: &[String]) -> bool {
+    !rule.anchored
+        || rule
+            .literal_prefix
+            .iter()
+            .zip(components)
+            .all(|(prefix, component)| prefix == component)
+}
+
+/// A rule matches the path itself or any of its ancestor directories
+fn rule_matches(rule: &Rule, components: &[String]) -> bool {
+    (1..=components.len()).any(|depth| {
+        if rule.anchored {
+            rule.pattern
+                .matches_with(&components[..depth].join("/"), MATCH_OPTIONS)
+        } else {
+            rule.pattern
+                .matches_with(&components[depth - 1], MATCH_OPTIONS)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unanchored_pattern_matches_at_any_depth() {
+        let ignore = AirisIgnore::parse("# keep local env\n.env.dev\n").unwrap();
+        assert!(ignore.is_ignored(Path::new(".env.dev")));
+        assert!(ignore.is_ignored(Path::new("apps/web/.env.dev")));
+        assert!(!ignore.is_ignored(Path::new(".env")));
+    }
+
+    #[test]
+    fn test_anchored_directory_covers_contents() {
+        let ignore = AirisIgnore::parse("/fixtures/\nlibs/*/dist\n").unwrap();
+        assert!(ignore.is_ignored(Path::new("fixtures")));
+        assert!(ignore.is_ignored(Path::new("./fixtures/large/data.bin")));
+        assert!(!ignore.is_ignored(Path::new("apps/fixtures")));
+        assert!(ignore.is_ignored(Path::new("libs/ui/dist")));
+        assert!(!ignore.is_ignored(Path::new("apps/web/dist")));
+    }
+
+    #[test]
+    fn test_negation_last_match_wins() {
+        let ignore = AirisIgnore::parse("node_modules\n!apps/web/node_modules\n").unwrap();
+        assert!(ignore.is_ignored(Path::new("libs/ui/node_modules")));
+        assert!(!ignore.is_ignored(Path::new("apps/web/node_modules")));
+    }
+
+    #[test]
+    fn test_has_ignored_descendant() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("dist/assets")).unwrap();
+        fs::write(dir.path().join("dist/keep.me"), "").unwrap();
+        fs::write(dir.path().join("dist/assets/app.js"), "").unwrap();
+
+        let ignore = AirisIgnore::parse(
+            "dist/keep.me
+",
+        )
+        .unwrap();
+        assert!(!ignore.is_ignored(Path::new("dist")));
+        assert!(ignore.has_ignored_descendant(dir.path(), Path::new("dist")));
+        assert!(!ignore.has_ignored_descendant(dir.path(), Path::new("dist/assets")));
+
+        let ignore = AirisIgnore::parse(
+            "dist/keep.me
+!dist/keep.me
+",
+        )
+        .unwrap();
+        assert!(!ignore.has_ignored_descendant(dir.path(), Path::new("dist")));
+    }
+
+    #[test]
+    fn test_could_match_under_follows_literal_prefix() {
+        let ignore =
+            AirisIgnore::parse("apps/web/dist/keep.me\nlibs/*/fixtures\n.env.dev\n").unwrap();
+        let under = |rule: usize, path: &str| {
+            could_match_under(&ignore.rules[rule], &normal_components(Path::new(path)))
+        };
+        assert!(under(0, "apps"));
+        assert!(under(0, "apps/web/dist"));
+        assert!(!under(0, "apps/api/node_modules"));
+        assert!(!under(0, "node_modules"));
+        assert!(under(1, "libs/ui/node_modules"));
+        assert!(!under(1, "apps/web"));
+        // Basename patterns can match anywhere
+        assert!(under(2, "node_modules"));
+    }
+
+    #[test]
+    fn test_missing_file_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(AirisIgnore::load(dir.path()).unwrap().is_empty());
+    }
+}
@@ -11,6 +11,7 @@
 //! ```
 
 use anyhow::{Result, bail};
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use std::process::Command;
 
@@ -267,10 +268,56 @@ pub fn resolve_python() -> Toolchain {
     }
 }
 
+/// Rewrite a default base image to pull from a configured mirror.
+///
+/// `images` is the manifest's `[build.images]` table keyed by runtime family
+/// (`node`, `rust`, `python`, `bun`, `deno`). A prefix such as
+/// `registry.corp/mirror/node` replaces the image repository and keeps the
+/// tag (`node:24-alpine` → `registry.corp/mirror/node:24-alpine`); a prefix
+/// ending in `/` is prepended to the whole image. Without an entry for the
+/// family, the image is returned unchanged.
+pub fn mirror_image(image: &str, family: &str, images: &IndexMap<String, String>) -> String {
+    let Some(prefix) = images.get(family).filter(|p| !p.is_empty()) else {
+        return image.to_string();
+    };
+
+    if prefix.ends_with('/') {
+        return format!("{}{}", prefix, image);
+    }
+
+    // Only a ':' after the last '/' is a tag (registry hosts may carry ports)
+    let repo_start = image.rfind('/').map_or(0, |i| i + 1);
+    match image[repo_start..].find(':') {
+        Some(i) => format!("{}{}", prefix, &image[repo_start + i..]),
+        None => prefix.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_mirror_image() {
+        let mut images = IndexMap::new();
+        images.insert("node".to_string(), "registry.corp/mirror/node".to_string());
+        images.insert("python".to_string(), "registry.corp:5000/".to_string());
+
+        assert_eq!(
+            mirror_image("node:24-alpine", "node", &images),
+            "registry.corp/mirror/node:24-alpine"
+        );
+        assert_eq!(
+            mirror_image("python:3.13-slim", "python", &images),
+            "registry.corp:5000/python:3.13-slim"
+        );
+        // No mirror configured for this family
+        assert_eq!(
+            mirror_image("rust:1.88-slim", "rust", &images),
+            "rust:1.88-slim"
+        );
+    }
+
     #[test]
     fn test_parse_channel() {
         assert_eq!(RuntimeChannel::parse("lts").unwrap(), RuntimeChannel::Lts);
@@ -25,6 +25,14 @@ pub struct Cli {
     #[arg(short = 'V', long = "version")]
     pub version: bool,
 
+    /// Disable colored output (also honors NO_COLOR; off when stdout isn't a TTY)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Suppress banners and progress output; errors and results still print
+    #[arg(short = 'q', long, global = true)]
+    pub quiet: bool,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -62,7 +70,11 @@ pub enum Commands {
     },
 
     /// Run system health checks
-    Verify,
+    Verify {
+        /// Also probe each app's health endpoint on the running stack
+        #[arg(long)]
+        live: bool,
+    },
 
     /// Diagnose workspace configuration and show actionable fixes.
     Doctor {
@@ -114,8 +126,15 @@ pub enum Commands {
         minor: bool,
         #[arg(long)]
         patch: bool,
+        /// Detect the bump from commits since the last tag (default)
         #[arg(long)]
         auto: bool,
+        /// Commit the bump as `release: v<version>` and tag that commit
+        #[arg(long)]
+        tag: bool,
+        /// Push the new tag to origin
+        #[arg(long, requires = "tag")]
+        push: bool,
     },
 
     /// Regenerate workspace files
@@ -123,10 +142,21 @@ pub enum Commands {
     Gen {
         #[arg(long)]
         dry_run: bool,
+        /// Remove legacy compose variants
         #[arg(long)]
         force: bool,
+        /// Overwrite generated files that are user-owned (the previous
+        /// content is backed up)
+        #[arg(long)]
+        overwrite_user_files: bool,
         #[arg(long)]
         migrate: bool,
+        /// Only run these generators (comma-separated: compose, devcontainer, tsconfig, ai)
+        #[arg(long, value_delimiter = ',')]
+        only: Vec<String>,
+        /// Skip these generators (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        except: Vec<String>,
     },
 
     /// Generate code and types
@@ -163,7 +193,8 @@ pub enum Commands {
         version: Option<String>,
     },
 
-    /// Generate shell completion scripts
+    /// Generate shell completion scripts (bash, zsh, fish, powershell, elvish)
+    #[command(visible_alias = "completions")]
     Completion {
         #[arg(value_enum)]
         shell: clap_complete::Shell,
@@ -171,6 +202,10 @@ pub enum Commands {
 
     /// Start the MCP server
     Mcp,
+
+    /// Run an extension: `.airis/bin/airis-<name>`, then `airis-<name>` on PATH
+    #[command(external_subcommand)]
+    External(Vec<String>),
 }
 
 #[derive(Args)]
@@ -196,7 +231,22 @@ pub enum PolicyCommands {
 pub enum DepsCommands {
     Tree,
     Json,
-    Show { package: String },
+    Show {
+        package: String,
+    },
+    /// Export the dependency graph (pipe DOT into `dot -Tsvg`)
+    Graph {
+        /// Output format
+        #[arg(long, default_value = "dot", value_parser = ["dot", "json"])]
+        format: String,
+    },
+    /// List every package that transitively depends on <package>
+    Dependents {
+        package: String,
+        /// Output as a JSON array of package IDs
+        #[arg(long)]
+        json: bool,
+    },
     Check,
 }
 
@@ -231,6 +281,33 @@ pub enum ManifestCommands {
     Rule { name: String },
     #[command(name = "json")]
     Json,
+    /// Import root package.json scripts into [commands]
+    #[command(name = "import-scripts")]
+    ImportScripts {
+        /// Keep scripts as plain shell instead of `docker compose exec` form
+        #[arg(long)]
+        raw: bool,
+    },
+    /// Print the value at a dotted path (e.g. workspace.package_manager)
+    #[command(name = "get")]
+    Get {
+        /// Dotted key path; array elements by index (e.g. app.0.name)
+        path: String,
+        /// Print this instead of failing when the path is missing
+        #[arg(long)]
+        default: Option<String>,
+    },
+    /// Set the value at a dotted path, keeping comments and formatting
+    #[command(name = "set")]
+    Set {
+        /// Dotted key path (e.g. ci.auto_merge.enabled)
+        path: String,
+        /// New value (bool/integer/string inferred; see --json)
+        value: String,
+        /// Parse the value as JSON (arrays, tables)
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -248,15 +325,45 @@ pub enum ValidateCommands {
 
 #[derive(Subcommand)]
 pub enum GenerateCommands {
+    /// renovate.json or .github/dependabot.yml aligned with the pnpm catalog
+    #[command(name = "deps-config")]
+    DepsConfig {
+        #[arg(long, default_value = "renovate", value_parser = ["renovate", "dependabot"])]
+        target: String,
+    },
+    /// TypeScript types and a fetch client from an OpenAPI 3 spec
+    Openapi {
+        /// Spec file (JSON/YAML) or http(s) URL
+        #[arg(short, long)]
+        input: String,
+        #[arg(short, long, default_value = "libs/api-types")]
+        output: String,
+    },
     Types {
+        /// Database engine to introspect
+        #[arg(long, default_value = "postgres", value_parser = ["postgres", "mysql"])]
+        engine: String,
         #[arg(long, default_value = "localhost")]
         host: String,
-        #[arg(long, default_value = "54322")]
-        port: String,
-        #[arg(long, default_value = "postgres")]
-        database: String,
+        /// Default: 54322 (postgres) / 3306 (mysql)
+        #[arg(long)]
+        port: Option<String>,
+        /// Default: postgres (postgres); required for mysql
+        #[arg(long)]
+        database: Option<String>,
+        /// Default: postgres (postgres) / root (mysql)
+        #[arg(long)]
+        user: Option<String>,
+        #[arg(long)]
+        password: Option<String>,
         #[arg(short, long, default_value = "libs/types")]
         output: String,
+        /// Also write <table>.schema.ts Zod schemas
+        #[arg(long)]
+        zod: bool,
+        /// Regenerate whenever the schema changes (Ctrl-C to stop)
+        #[arg(long)]
+        watch: bool,
     },
 }
 
@@ -266,16 +373,25 @@ pub enum NewCommands {
         name: String,
         #[arg(short, long, default_value = "hono")]
         runtime: String,
+        /// Don't add an [[app]] entry to manifest.toml
+        #[arg(long)]
+        no_register: bool,
     },
     Web {
         name: String,
         #[arg(short, long, default_value = "nextjs")]
         runtime: String,
+        /// Don't add an [[app]] entry to manifest.toml
+        #[arg(long)]
+        no_register: bool,
     },
     Lib {
         name: String,
         #[arg(short, long, default_value = "ts")]
         runtime: String,
+        /// Don't add an [[app]] entry to manifest.toml
+        #[arg(long)]
+        no_register: bool,
     },
     Edge {
         name: String,
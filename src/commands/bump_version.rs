@@ -6,18 +6,22 @@ use std::path::Path;
 use std::process::Command;
 
 use crate::manifest::{MANIFEST_FILE, Manifest, VersioningStrategy};
+use crate::safe_fs::SafeFS;
+
+const CHANGELOG_FILE: &str = "CHANGELOG.md";
 
 #[derive(Debug, Clone)]
 pub enum BumpMode {
-    Auto,  // Detect from commit message
+    Auto,  // Detect from commits since the last tag
     Major, // x.0.0
     Minor, // x.y.0
     Patch, // x.y.z
 }
 
 /// Bump version in Cargo.toml only (manifest.toml is NEVER modified)
-/// Version source of truth is git tags
-pub fn run(mode: BumpMode) -> Result<()> {
+/// Version source of truth is git tags; `tag` commits the bumped files as
+/// `release: v<version>`, tags that commit, and `push` sends the tag to origin.
+pub fn run(mode: BumpMode, tag: bool, push: bool) -> Result<()> {
     let manifest_path = Path::new(MANIFEST_FILE);
 
     // Load manifest for versioning strategy only
@@ -34,27 +38,47 @@ pub fn run(mode: BumpMode) -> Result<()> {
     let current_version =
         get_cargo_version()?.ok_or_else(|| anyhow::anyhow!("❌ No version found in Cargo.toml"))?;
 
+    let strategy = manifest
+        .as_ref()
+        .map(|m| m.versioning.strategy.clone())
+        .unwrap_or(VersioningStrategy::Auto);
+
+    // Commits since the last tag drive both the bump and the changelog
+    let changes = if matches!(strategy, VersioningStrategy::ConventionalCommits) {
+        ChangelogGroups::from_messages(&commits_since_last_tag()?)
+    } else {
+        ChangelogGroups::default()
+    };
+
     // Determine bump type
     let new_version = match mode {
         BumpMode::Auto => {
-            // Detect from last commit message or versioning strategy
-            let strategy = manifest
-                .as_ref()
-                .map(|m| m.versioning.strategy.clone())
-                .unwrap_or(VersioningStrategy::Auto);
-
+            // Detect from commit history or versioning strategy
             match strategy {
                 VersioningStrategy::Manual => {
                     bail!("❌ Versioning strategy is 'manual'. Use --major, --minor, or --patch.");
                 }
                 VersioningStrategy::Auto => {
-                    // Default to minor bump
-                    bump_version_string(&current_version, "minor")?
-                }
-                VersioningStrategy::ConventionalCommits => {
-                    let commit_msg = get_pending_commit_message()?;
-                    detect_bump_type_from_conventional_commit(&commit_msg, &current_version)?
+                    let commits = commits_since_last_tag()?;
+                    match detect_bump_type_from_commits(&commits) {
+                        Some(bump_type) => bump_version_string(&current_version, bump_type)?,
+                        None => {
+                            println!(
+                                "{}",
+                                "⚠️  No conventional commits since the last tag, defaulting to a patch bump"
+                                    .yellow()
+                            );
+                            bump_version_string(&current_version, "patch")?
+                        }
+                    }
                 }
+                VersioningStrategy::ConventionalCommits => match changes.bump_type() {
+                    Some(bump_type) => bump_version_string(&current_version, bump_type)?,
+                    None => {
+                        let commit_msg = get_pending_commit_message()?;
+                        detect_bump_type_from_conventional_commit(&commit_msg, &current_version)?
+                    }
+                },
             }
         }
         BumpMode::Major => bump_version_string(&current_version, "major")?,
@@ -62,6 +86,15 @@ pub fn run(mode: BumpMode) -> Result<()> {
         BumpMode::Patch => bump_version_string(&current_version, "patch")?,
     };
 
+    // Refuse before touching any file if the tag is already taken
+    let tag_name = format!("v{}", new_version);
+    if tag && tag_exists(&tag_name)? {
+        bail!(
+            "❌ Tag {} already exists. Delete it or bump to a different version.",
+            tag_name
+        );
+    }
+
     println!(
         "🚀 Bumping version: {} → {}",
         current_version.yellow(),
@@ -72,12 +105,326 @@ pub fn run(mode: BumpMode) -> Result<()> {
     update_cargo_toml(&new_version)?;
     let lock_updated = update_cargo_lock(&new_version)?;
 
+    let mut bumped_files = Vec::new();
+    if Path::new("Cargo.toml").exists() {
+        bumped_files.push("Cargo.toml");
+    }
+    if lock_updated {
+        bumped_files.push("Cargo.lock");
+    }
+
     println!("✅ Version bumped successfully!");
     println!("   Cargo.toml: {}", new_version.green());
     if lock_updated {
         println!("   Cargo.lock: {}", new_version.green());
     }
 
+    if matches!(strategy, VersioningStrategy::ConventionalCommits) {
+        if changes.is_empty() {
+            println!(
+                "   {}: {}",
+                CHANGELOG_FILE,
+                "no conventional commits since last tag, skipped".dimmed()
+            );
+        } else {
+            let date = chrono::Local::now().format("%Y-%m-%d").to_string();
+            update_changelog(&new_version, &date, &changes)?;
+            bumped_files.push(CHANGELOG_FILE);
+            println!("   {}: {}", CHANGELOG_FILE, new_version.green());
+        }
+    }
+
+    if tag {
+        let message = fs::read_to_string(CHANGELOG_FILE)
+            .ok()
+            .and_then(|content| changelog_section(&content, &new_version))
+            .unwrap_or_else(|| format!("Release {}", tag_name));
+        // The tag must point at the bumped version, not the commit before it
+        commit_release(&tag_name, &bumped_files)?;
+        println!("📝 Committed release: {}", tag_name.green());
+        create_tag(&tag_name, &message)?;
+        println!("🏷️  Tagged {}", tag_name.green());
+
+        if push {
+            push_tag(&tag_name)?;
+            println!("📤 Pushed {} to origin", tag_name.green());
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether a tag with this name already exists locally
+fn tag_exists(tag_name: &str) -> Result<bool> {
+    let status = Command::new("git")
+        .args(["rev-parse", "-q", "--verify"])
+        .arg(format!("refs/tags/{}", tag_name))
+        .output()
+        .with_context(|| "Failed to run git rev-parse")?
+        .status;
+    Ok(status.success())
+}
+
+/// Commit only the bumped files as `release: <tag>`
+fn commit_release(tag_name: &str, files: &[&str]) -> Result<()> {
+    if files.is_empty() {
+        bail!(
+            "❌ Nothing to commit for {}: no Cargo.toml or CHANGELOG.md was updated",
+            tag_name
+        );
+    }
+
+    let output = Command::new("git")
+        .arg("add")
+        .arg("--")
+        .args(files)
+        .output()
+        .with_context(|| "Failed to run git add")?;
+    if !output.status.success() {
+        bail!(
+            "❌ Failed to stage {}: {}",
+            files.join(", "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let output = Command::new("git")
+        .args(["commit", "-m", &format!("release: {}", tag_name), "--"])
+        .args(files)
+        .output()
+        .with_context(|| "Failed to run git commit")?;
+    if !output.status.success() {
+        bail!(
+            "❌ Failed to commit release {}: {}",
+            tag_name,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+/// Create an annotated tag on HEAD
+fn create_tag(tag_name: &str, message: &str) -> Result<()> {
+    let output = Command::new("git")
+        .args(["tag", "-a", tag_name, "-m", message])
+        .output()
+        .with_context(|| "Failed to run git tag")?;
+
+    if !output.status.success() {
+        bail!(
+            "❌ Failed to create tag {}: {}",
+            tag_name,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+/// Push a single tag to origin
+fn push_tag(tag_name: &str) -> Result<()> {
+    let output = Command::new("git")
+        .args(["push", "origin"])
+        .arg(format!("refs/tags/{}", tag_name))
+        .output()
+        .with_context(|| "Failed to run git push")?;
+
+    if !output.status.success() {
+        bail!(
+            "❌ Failed to push tag {}: {}",
+            tag_name,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+/// Extract the body of the `## [version]` section from a changelog, used as
+/// the annotated tag message.
+fn changelog_section(changelog: &str, version: &str) -> Option<String> {
+    let heading = format!("## [{}]", version);
+    let mut lines = changelog
+        .lines()
+        .skip_while(|line| !line.starts_with(&heading));
+    lines.next()?;
+
+    let body = lines
+        .take_while(|line| !line.starts_with("## "))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let body = body.trim();
+
+    (!body.is_empty()).then(|| format!("Release v{}\n\n{}", version, body))
+}
+
+/// Conventional commits since the last tag, grouped by changelog section
+#[derive(Debug, Default, PartialEq)]
+struct ChangelogGroups {
+    breaking: Vec<String>,
+    features: Vec<String>,
+    fixes: Vec<String>,
+    chores: Vec<String>,
+}
+
+impl ChangelogGroups {
+    /// Group full commit messages by type. Messages without a
+    /// `type(scope)!: description` subject are ignored.
+    fn from_messages(messages: &[String]) -> Self {
+        let subject_re =
+            Regex::new(r"^([a-z]+)(?:\(([^)]*)\))?(!)?:\s*(.+)$").expect("valid regex");
+        let mut groups = Self::default();
+
+        for message in messages {
+            let subject = message.lines().next().unwrap_or("").trim();
+            let Some(caps) = subject_re.captures(subject) else {
+                continue;
+            };
+
+            let kind = &caps[1];
+            let description = &caps[4];
+            let entry = match caps.get(2) {
+                Some(scope) if !scope.as_str().is_empty() => {
+                    format!("**{}:** {}", scope.as_str(), description)
+                }
+                _ => description.to_string(),
+            };
+
+            if caps.get(3).is_some() || message.contains("BREAKING CHANGE") {
+                groups.breaking.push(entry);
+            } else if kind == "feat" {
+                groups.features.push(entry);
+            } else if kind == "fix" {
+                groups.fixes.push(entry);
+            } else {
+                groups.chores.push(entry);
+            }
+        }
+
+        groups
+    }
+
+    fn is_empty(&self) -> bool {
+        self.breaking.is_empty()
+            && self.features.is_empty()
+            && self.fixes.is_empty()
+            && self.chores.is_empty()
+    }
+
+    /// Highest bump implied by the grouped commits
+    fn bump_type(&self) -> Option<&'static str> {
+        if !self.breaking.is_empty() {
+            Some("major")
+        } else if !self.features.is_empty() {
+            Some("minor")
+        } else if self.is_empty() {
+            None
+        } else {
+            Some("patch")
+        }
+    }
+}
+
+/// Classify a list of commit messages: Major for `BREAKING CHANGE` or `!`,
+/// Minor for any `feat`, else Patch. `None` when no message is conventional.
+fn detect_bump_type_from_commits(messages: &[String]) -> Option<&'static str> {
+    ChangelogGroups::from_messages(messages).bump_type()
+}
+
+/// Full commit messages since the most recent tag (all history if untagged)
+fn commits_since_last_tag() -> Result<Vec<String>> {
+    let tag = Command::new("git")
+        .args(["describe", "--tags", "--abbrev=0"])
+        .output()
+        .with_context(|| "Failed to run git describe")?;
+
+    let range = if tag.status.success() {
+        format!("{}..HEAD", String::from_utf8_lossy(&tag.stdout).trim())
+    } else {
+        "HEAD".to_string()
+    };
+
+    // NUL-separate messages so multi-line bodies stay intact
+    let output = Command::new("git")
+        .args(["log", &range, "--format=%B%x00"])
+        .output()
+        .with_context(|| "Failed to get git log")?;
+
+    if !output.status.success() {
+        // Fresh repository without commits
+        return Ok(Vec::new());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .map(|msg| msg.trim().to_string())
+        .filter(|msg| !msg.is_empty())
+        .collect())
+}
+
+/// Render a Keep a Changelog section for the new version
+fn render_changelog_section(version: &str, date: &str, groups: &ChangelogGroups) -> String {
+    let mut section = format!("## [{}] - {}\n", version, date);
+
+    for (title, entries) in [
+        ("Breaking Changes", &groups.breaking),
+        ("Features", &groups.features),
+        ("Bug Fixes", &groups.fixes),
+        ("Chores", &groups.chores),
+    ] {
+        if entries.is_empty() {
+            continue;
+        }
+        section.push_str(&format!("\n### {}\n\n", title));
+        for entry in entries {
+            section.push_str(&format!("- {}\n", entry));
+        }
+    }
+
+    section
+}
+
+/// Insert a release section above the newest released version, keeping the
+/// header and any `## [Unreleased]` section on top.
+fn insert_changelog_section(existing: &str, section: &str) -> String {
+    if existing.trim().is_empty() {
+        return format!("# Changelog\n\n{}", section);
+    }
+
+    let mut offset = 0;
+    for line in existing.split_inclusive('\n') {
+        if line.starts_with("## ") && !line.to_lowercase().starts_with("## [unreleased]") {
+            return format!(
+                "{}{}\n{}",
+                &existing[..offset],
+                section,
+                &existing[offset..]
+            );
+        }
+        offset += line.len();
+    }
+
+    let separator = if existing.ends_with("\n\n") {
+        ""
+    } else if existing.ends_with('\n') {
+        "\n"
+    } else {
+        "\n\n"
+    };
+    format!("{}{}{}", existing, separator, section)
+}
+
+/// Prepend the release section to CHANGELOG.md (backed up via SafeFS)
+fn update_changelog(version: &str, date: &str, groups: &ChangelogGroups) -> Result<()> {
+    let path = Path::new(CHANGELOG_FILE);
+    let existing = if path.exists() {
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", CHANGELOG_FILE))?
+    } else {
+        String::new()
+    };
+
+    let section = render_changelog_section(version, date, groups);
+    let updated = insert_changelog_section(&existing, &section);
+
+    SafeFS::current(false)?.edit(path, updated)?;
     Ok(())
 }
 
@@ -403,4 +750,142 @@ mod tests {
         let updated = replace_lock_version(lock, "airis-workspace", "9.9.9").unwrap();
         assert_eq!(updated, lock);
     }
+
+    #[test]
+    fn test_changelog_groups_from_messages() {
+        let messages: Vec<String> = [
+            "feat(cli): add graph command",
+            "fix: handle empty lockfile",
+            "chore: bump deps",
+            "refactor!: drop legacy config",
+            "docs: typo\n\nBREAKING CHANGE: none really",
+            "Merge branch 'main'",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+        let groups = ChangelogGroups::from_messages(&messages);
+        assert_eq!(groups.features, vec!["**cli:** add graph command"]);
+        assert_eq!(groups.fixes, vec!["handle empty lockfile"]);
+        assert_eq!(groups.chores, vec!["bump deps"]);
+        assert_eq!(groups.breaking, vec!["drop legacy config", "typo"]);
+        assert_eq!(groups.bump_type(), Some("major"));
+    }
+
+    #[test]
+    fn test_changelog_groups_bump_type() {
+        let only = |msg: &str| ChangelogGroups::from_messages(&[msg.to_string()]);
+        assert_eq!(only("feat: x").bump_type(), Some("minor"));
+        assert_eq!(only("fix: x").bump_type(), Some("patch"));
+        assert_eq!(only("ci: x").bump_type(), Some("patch"));
+        assert_eq!(only("not conventional").bump_type(), None);
+        assert!(only("not conventional").is_empty());
+    }
+
+    #[test]
+    fn test_render_changelog_section_skips_empty_groups() {
+        let groups = ChangelogGroups {
+            features: vec!["add graph".into()],
+            fixes: vec!["fix cycle".into()],
+            ..Default::default()
+        };
+        let section = render_changelog_section("1.2.0", "2026-01-02", &groups);
+        assert_eq!(
+            section,
+            "## [1.2.0] - 2026-01-02\n\n### Features\n\n- add graph\n\n### Bug Fixes\n\n- fix cycle\n"
+        );
+    }
+
+    #[test]
+    fn test_insert_changelog_section_below_unreleased() {
+        let existing =
+            "# Changelog\n\n## [Unreleased]\n\n- wip\n\n## [1.0.0] - 2025-01-01\n\n- first\n";
+        let updated = insert_changelog_section(existing, "## [1.1.0] - 2026-01-01\n");
+        assert_eq!(
+            updated,
+            "# Changelog\n\n## [Unreleased]\n\n- wip\n\n## [1.1.0] - 2026-01-01\n\n## [1.0.0] - 2025-01-01\n\n- first\n"
+        );
+
+        // No released section yet: append after the header
+        let updated = insert_changelog_section("# Changelog\n", "## [0.1.0] - 2026-01-01\n");
+        assert_eq!(updated, "# Changelog\n\n## [0.1.0] - 2026-01-01\n");
+
+        // Missing file
+        let updated = insert_changelog_section("", "## [0.1.0] - 2026-01-01\n");
+        assert_eq!(updated, "# Changelog\n\n## [0.1.0] - 2026-01-01\n");
+    }
+
+    #[test]
+    fn test_changelog_section_extracts_tag_message() {
+        let changelog = "# Changelog\n\n## [Unreleased]\n\n## [1.1.0] - 2026-01-01\n\n### Features\n\n- add graph\n\n## [1.0.0] - 2025-01-01\n\n- first\n";
+        assert_eq!(
+            changelog_section(changelog, "1.1.0").unwrap(),
+            "Release v1.1.0\n\n### Features\n\n- add graph"
+        );
+        assert!(changelog_section(changelog, "2.0.0").is_none());
+        assert!(changelog_section("## [3.0.0] - 2026-01-01\n", "3.0.0").is_none());
+    }
+
+    #[test]
+    fn test_detect_bump_type_from_commits() {
+        let commits = |msgs: &[&str]| msgs.iter().map(|m| m.to_string()).collect::<Vec<_>>();
+
+        let major = commits(&["fix: a", "feat: b", "chore: c\n\nBREAKING CHANGE: d"]);
+        assert_eq!(detect_bump_type_from_commits(&major), Some("major"));
+
+        let major_bang = commits(&["fix(api)!: drop v1", "feat: b"]);
+        assert_eq!(detect_bump_type_from_commits(&major_bang), Some("major"));
+
+        let minor = commits(&["fix: a", "feat(cli): b", "docs: c"]);
+        assert_eq!(detect_bump_type_from_commits(&minor), Some("minor"));
+
+        let patch = commits(&["fix: a", "refactor: b"]);
+        assert_eq!(detect_bump_type_from_commits(&patch), Some("patch"));
+
+        // No commits, or nothing conventional (capitalized prefixes don't count)
+        assert_eq!(detect_bump_type_from_commits(&[]), None);
+        let plain = commits(&["Update README", "Note: tweak wording"]);
+        assert_eq!(detect_bump_type_from_commits(&plain), None);
+    }
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8_lossy(&output.stdout).to_string()
+    }
+
+    #[test]
+    fn test_tag_points_at_bumped_version() {
+        let _guard = crate::test_lock::DIR_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        git(root, &["init", "-q"]);
+        git(root, &["config", "user.name", "test"]);
+        git(root, &["config", "user.email", "test@example.com"]);
+        git(root, &["config", "tag.gpgSign", "false"]);
+        git(root, &["config", "commit.gpgSign", "false"]);
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"1.2.3\"\n",
+        )
+        .unwrap();
+        git(root, &["add", "Cargo.toml"]);
+        git(root, &["commit", "-q", "-m", "init"]);
+
+        let original = std::env::current_dir().unwrap();
+        std::env::set_current_dir(root).unwrap();
+        let result = run(BumpMode::Patch, true, false);
+        std::env::set_current_dir(original).unwrap();
+        result.unwrap();
+
+        let tagged = git(root, &["show", "v1.2.4:Cargo.toml"]);
+        assert!(tagged.contains("version = \"1.2.4\""));
+        let subject = git(root, &["log", "-1", "--format=%s", "v1.2.4"]);
+        assert_eq!(subject.trim(), "release: v1.2.4");
+    }
 }
@@ -12,8 +12,9 @@ use anyhow::{Result, anyhow};
 use colored::Colorize;
 use glob::glob;
 
+use crate::airisignore::{AIRISIGNORE_FILE, AirisIgnore};
 use crate::manifest::{MANIFEST_FILE, Manifest};
-use crate::safe_fs::{SafeAction, SafeFS};
+use crate::safe_fs::{SafeAction, SafeFS, SafeOpResult};
 
 /// Project-root markers used to decide whether the current directory is a
 /// reasonable place to run a destructive cleanup.
@@ -111,28 +112,34 @@ pub fn run(dry_run: bool, purge: bool, allow_anywhere: bool) -> Result<()> {
     };
 
     let safe_fs = SafeFS::current(dry_run)?;
+    let ignore = AirisIgnore::load(&std::env::current_dir()?)?;
 
     if dry_run {
-        println!(
+        crate::progress!(
             "{}",
             "🔍 Dry-run mode: showing what would be cleaned...".bright_blue()
         );
     } else {
-        println!("{}", "🧹 Cleaning workspace...".bright_blue());
+        crate::progress!("{}", "🧹 Cleaning workspace...".bright_blue());
     }
-    println!();
+    crate::progress!();
 
     let mut cleaned = 0;
     let mut skipped = 0;
     let mut errors = 0;
+    let mut reclaimed: u64 = 0;
 
     // 1. Build Artifacts Clean (Standard)
-    println!("{}", "📦 Build Artifacts".bold());
+    crate::progress!("{}", "📦 Build Artifacts".bold());
     let clean = &manifest.workspace.clean;
     for dir in &clean.dirs {
+        if is_kept(&ignore, Path::new(dir), &mut skipped) {
+            continue;
+        }
         match safe_fs.clean_artifact(dir) {
             Ok(result) => {
-                print_result(&result.action, dir, &mut cleaned, &mut skipped);
+                print_result(&result, dir, &mut cleaned, &mut skipped);
+                reclaimed += result.reclaimed;
             }
             Err(e) => {
                 println!("   {} {} - {}", "✗".red(), dir, e);
@@ -143,7 +150,7 @@ pub fn run(dry_run: bool, purge: bool, allow_anywhere: bool) -> Result<()> {
 
     // 2. Legacy / Purge Clean (Optional)
     if purge {
-        println!("\n{}", "💀 Legacy Config Purge".bold());
+        crate::progress!("\n{}", "💀 Legacy Config Purge".bold());
         let legacy_patterns = [
             "docker-compose.yml",
             "docker-compose.yaml",
@@ -191,6 +198,9 @@ pub fn run(dry_run: bool, purge: bool, allow_anywhere: bool) -> Result<()> {
                     {
                         continue;
                     }
+                    if is_kept(&ignore, &entry, &mut skipped) {
+                        continue;
+                    }
 
                     if dry_run {
                         println!(
@@ -217,7 +227,7 @@ pub fn run(dry_run: bool, purge: bool, allow_anywhere: bool) -> Result<()> {
     }
 
     // 3. Recursive Artifacts
-    println!("\n{}", "📂 Recursive Artifacts".bold());
+    crate::progress!("\n{}", "📂 Recursive Artifacts".bold());
     for pattern in &clean.recursive {
         // Validate pattern is safe
         if pattern.contains("..") || pattern.starts_with('/') {
@@ -245,15 +255,19 @@ pub fn run(dry_run: bool, purge: bool, allow_anywhere: bool) -> Result<()> {
                     {
                         continue;
                     }
+                    if is_kept(&ignore, &entry, &mut skipped) {
+                        continue;
+                    }
 
                     match safe_fs.clean_artifact(&entry) {
                         Ok(result) => {
                             print_result(
-                                &result.action,
+                                &result,
                                 &entry.to_string_lossy(),
                                 &mut cleaned,
                                 &mut skipped,
                             );
+                            reclaimed += result.reclaimed;
                         }
                         Err(e) => {
                             println!("   {} {} - {}", "✗".red(), entry.display(), e);
@@ -272,12 +286,11 @@ pub fn run(dry_run: bool, purge: bool, allow_anywhere: bool) -> Result<()> {
     // Clean .DS_Store files (macOS artifacts)
     if let Ok(paths) = glob("**/.DS_Store") {
         for entry in paths.flatten() {
-            if entry.components().count() <= 5 {
+            if entry.components().count() <= 5 && !ignore.is_ignored(&entry) {
                 match safe_fs.clean_artifact(&entry) {
                     Ok(result) => {
-                        if matches!(result.action, SafeAction::Deleted | SafeAction::WouldDelete) {
-                            // Don't count .DS_Store in main stats, just clean silently
-                        }
+                        // Don't count .DS_Store in main stats, just clean silently
+                        reclaimed += result.reclaimed;
                     }
                     Err(_) => {
                         // Ignore errors for .DS_Store
@@ -296,6 +309,12 @@ pub fn run(dry_run: bool, purge: bool, allow_anywhere: bool) -> Result<()> {
             cleaned,
             skipped
         );
+        println!(
+            "{} Would reclaim {} across {} item(s)",
+            "💾".cyan(),
+            format_bytes(reclaimed),
+            cleaned
+        );
         println!();
         println!(
             "Run {} to actually clean.",
@@ -313,6 +332,12 @@ pub fn run(dry_run: bool, purge: bool, allow_anywhere: bool) -> Result<()> {
             skipped,
             errors
         );
+        println!(
+            "{} Reclaimed {} across {} item(s)",
+            "💾".cyan(),
+            format_bytes(reclaimed),
+            cleaned
+        );
         println!("{}", "(container cache preserved)".dimmed());
     }
 
@@ -327,14 +352,45 @@ pub fn run(dry_run: bool, purge: bool, allow_anywhere: bool) -> Result<()> {
     Ok(())
 }
 
-fn print_result(action: &SafeAction, path: &str, cleaned: &mut usize, skipped: &mut usize) {
-    match action {
+/// Skip (and report) a path protected by `.airisignore`, or a directory
+/// holding one
+fn is_kept(ignore: &AirisIgnore, path: &Path, skipped: &mut usize) -> bool {
+    let reason = if ignore.is_ignored(path) {
+        "kept by"
+    } else if ignore.has_ignored_descendant(Path::new("."), path) {
+        "contains paths kept by"
+    } else {
+        return false;
+    };
+    println!(
+        "   {} {} ({} {})",
+        "⏭️".yellow(),
+        path.display(),
+        reason,
+        AIRISIGNORE_FILE
+    );
+    *skipped += 1;
+    true
+}
+
+fn print_result(result: &SafeOpResult, path: &str, cleaned: &mut usize, skipped: &mut usize) {
+    match &result.action {
         SafeAction::Deleted => {
-            println!("   {} {}", "✓".green(), path);
+            crate::progress!(
+                "   {} {} {}",
+                "✓".green(),
+                path,
+                format_bytes(result.reclaimed).dimmed()
+            );
             *cleaned += 1;
         }
         SafeAction::WouldDelete => {
-            println!("   {} {} (would delete)", "→".bright_blue(), path);
+            crate::progress!(
+                "   {} {} (would delete, {})",
+                "→".bright_blue(),
+                path,
+                format_bytes(result.reclaimed)
+            );
             *cleaned += 1;
         }
         SafeAction::Skipped(reason) => {
@@ -345,6 +401,22 @@ fn print_result(action: &SafeAction, path: &str, cleaned: &mut usize, skipped: &
     }
 }
 
+/// Human-readable size using binary units (e.g. "2.3 GB")
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
 /// Remove orphaned generated files (called by airis gen after generation).
 pub fn remove_orphaned_files(
     previous_paths: &[String],
@@ -449,7 +521,7 @@ mod tests {
         assert!(!is_protected_path(Path::new("dist")));
     }
 
-    use super::{PROJECT_ROOT_MARKERS, default_manifest, is_project_root};
+    use super::{PROJECT_ROOT_MARKERS, default_manifest, format_bytes, is_project_root};
     use tempfile::tempdir;
 
     #[test]
@@ -472,6 +544,13 @@ mod tests {
         assert!(!is_project_root(dir.path()));
     }
 
+    #[test]
+    fn format_bytes_uses_binary_units() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(2_469_606_195), "2.3 GB");
+    }
+
     #[test]
     fn default_manifest_provides_canonical_clean_lists() {
         let manifest = default_manifest();
@@ -1,4 +1,4 @@
-//! Tree display for dependency graph
+//! Tree and DOT display for dependency graph
 
 use colored::Colorize;
 use std::collections::HashSet;
@@ -38,3 +38,43 @@ pub(super) fn print_tree(
 
     visited.remove(node_id);
 }
+
+/// Render the graph as Graphviz DOT (`dot -Tsvg`).
+///
+/// Nodes and edges are sorted so output is stable; edges that are part of a
+/// cycle are drawn red.
+pub(super) fn render_dot(dag: &Dag, cycles: &[Vec<String>]) -> String {
+    let mut cycle_edges: HashSet<(&str, &str)> = HashSet::new();
+    for cycle in cycles {
+        for (i, from) in cycle.iter().enumerate() {
+            let to = &cycle[(i + 1) % cycle.len()];
+            cycle_edges.insert((from.as_str(), to.as_str()));
+        }
+    }
+
+    let mut ids: Vec<&String> = dag.nodes.keys().collect();
+    ids.sort();
+
+    let mut out = String::from("digraph workspace {\n    rankdir=LR;\n    node [shape=box];\n");
+    for id in &ids {
+        let shape = if id.starts_with("apps/") {
+            "box"
+        } else {
+            "ellipse"
+        };
+        out.push_str(&format!("    \"{}\" [shape={}];\n", id, shape));
+    }
+    for id in &ids {
+        let mut deps: Vec<&String> = dag.nodes[*id].deps.iter().collect();
+        deps.sort();
+        for dep in deps {
+            if cycle_edges.contains(&(id.as_str(), dep.as_str())) {
+                out.push_str(&format!("    \"{}\" -> \"{}\" [color=red];\n", id, dep));
+            } else {
+                out.push_str(&format!("    \"{}\" -> \"{}\";\n", id, dep));
+            }
+        }
+    }
+    out.push_str("}\n");
+    out
+}
@@ -1,24 +1,17 @@
 //! DAG loading and dependency map helpers
 
-use anyhow::{Context, Result};
+use anyhow::Result;
 use std::collections::HashMap;
 use std::path::Path;
 
 use crate::dag::{Dag, DagNode, build_dag};
-use crate::pnpm::{PnpmLock, build_workspace_map};
+use crate::lockfile::{declared_package_manager, load_workspace_map};
 
-/// Load DAG from pnpm-lock.yaml
+/// Load DAG from the workspace lockfile (pnpm, npm, or yarn)
 pub(super) fn load_dag() -> Result<Dag> {
-    let lock_path = Path::new("pnpm-lock.yaml");
-
-    if !lock_path.exists() {
-        anyhow::bail!(
-            "pnpm-lock.yaml not found. Run 'pnpm install' first or ensure you're in the workspace root."
-        );
-    }
-
-    let lock = PnpmLock::load(lock_path).context("Failed to parse pnpm-lock.yaml")?;
-    let workspace_map = build_workspace_map(&lock);
+    // Prefer the lockfile of the package manager declared in manifest.toml
+    let root = Path::new(".");
+    let workspace_map = load_workspace_map(root, &declared_package_manager(root))?;
     let dag = build_dag(&workspace_map);
 
     Ok(dag)
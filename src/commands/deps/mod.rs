@@ -15,7 +15,7 @@ use serde::Serialize;
 use std::collections::HashSet;
 
 use analysis::{check_architecture, detect_cycles};
-use display::print_tree;
+use display::{print_tree, render_dot};
 use graph::{build_dependents_map, find_package, load_dag};
 
 /// Dependency graph output for JSON serialization
@@ -140,6 +140,20 @@ pub fn json() -> Result<()> {
     Ok(())
 }
 
+/// Output dependency graph as Graphviz DOT or JSON
+pub fn graph(format: &str) -> Result<()> {
+    match format {
+        "dot" => {
+            let dag = load_dag()?;
+            let cycles = detect_cycles(&dag);
+            print!("{}", render_dot(&dag, &cycles));
+            Ok(())
+        }
+        "json" => json(),
+        other => anyhow::bail!("Unknown graph format '{}'. Use 'dot' or 'json'.", other),
+    }
+}
+
 /// Show dependencies for a specific package
 pub fn show(pkg: &str) -> Result<()> {
     let dag = load_dag()?;
@@ -199,6 +213,45 @@ pub fn show(pkg: &str) -> Result<()> {
     Ok(())
 }
 
+/// List every package that transitively depends on a package
+///
+/// Answers "if I change this lib, what must be retested?".
+pub fn dependents(pkg: &str, json: bool) -> Result<()> {
+    let dag = load_dag()?;
+    let node = find_package(&dag, pkg)?;
+    let dependents = dag.get_dependents(&node.id);
+
+    if json {
+        let ids: Vec<&str> = dependents.iter().map(|n| n.id.as_str()).collect();
+        println!("{}", serde_json::to_string_pretty(&ids)?);
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!("📦 Packages depending on {}", node.id)
+            .bright_blue()
+            .bold()
+    );
+    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━".bright_blue());
+    println!();
+
+    if dependents.is_empty() {
+        println!("  {}", "(none)".dimmed());
+    } else {
+        for dep in &dependents {
+            println!("  └── {}", dep.id);
+        }
+        println!();
+        println!(
+            "{}",
+            format!("Total: {} packages", dependents.len()).dimmed()
+        );
+    }
+
+    Ok(())
+}
+
 /// Check for circular dependencies
 pub fn check() -> Result<()> {
     let dag = load_dag()?;
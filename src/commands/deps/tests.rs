@@ -1,6 +1,7 @@
 //! Tests for the deps command
 
 use super::analysis::{check_architecture, detect_cycles};
+use super::display::render_dot;
 use super::graph::build_dependents_map;
 use crate::dag::{Dag, DagNode};
 
@@ -121,3 +122,35 @@ fn test_check_architecture_valid() {
     let violations = check_architecture(&dag);
     assert!(violations.is_empty());
 }
+
+#[test]
+fn test_render_dot_marks_cycle_edges_red() {
+    let mut dag = Dag::new();
+
+    dag.add_node(DagNode {
+        id: "apps/web".to_string(),
+        name: "web".to_string(),
+        path: "apps/web".to_string(),
+        deps: vec!["libs/a".to_string()],
+    });
+    dag.add_node(DagNode {
+        id: "libs/a".to_string(),
+        name: "a".to_string(),
+        path: "libs/a".to_string(),
+        deps: vec!["libs/b".to_string()],
+    });
+    dag.add_node(DagNode {
+        id: "libs/b".to_string(),
+        name: "b".to_string(),
+        path: "libs/b".to_string(),
+        deps: vec!["libs/a".to_string()],
+    });
+
+    let dot = render_dot(&dag, &detect_cycles(&dag));
+
+    assert!(dot.starts_with("digraph workspace {"));
+    assert!(dot.contains("\"apps/web\" [shape=box];"));
+    assert!(dot.contains("\"apps/web\" -> \"libs/a\";"));
+    assert!(dot.contains("\"libs/a\" -> \"libs/b\" [color=red];"));
+    assert!(dot.contains("\"libs/b\" -> \"libs/a\" [color=red];"));
+}
@@ -13,6 +13,7 @@ use anyhow::Context;
 use anyhow::Result;
 use std::path::Path;
 
+use crate::error::AirisError;
 use crate::manifest::{MANIFEST_FILE, Manifest};
 
 use compute::compute_diff;
@@ -74,11 +75,7 @@ pub fn run(format: DiffFormat) -> Result<()> {
     let manifest_path = Path::new(MANIFEST_FILE);
 
     if !manifest_path.exists() {
-        anyhow::bail!(
-            "manifest.toml not found.\n\n\
-             Hint: Create one (see docs/manifest.md) or ask Claude Code via /airis:init.\n\
-             This command requires an airis workspace."
-        );
+        return Err(AirisError::ManifestMissing.into());
     }
 
     let manifest = Manifest::load(manifest_path).context("Failed to load manifest.toml")?;
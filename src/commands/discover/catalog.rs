@@ -72,6 +72,17 @@ pub fn extract_catalog_from_path(base_path: &Path) -> Result<IndexMap<String, St
     Ok(catalog)
 }
 
+/// Read the `packageManager` field (e.g., "bun@1.1.0") from package.json in
+/// the given directory
+pub fn extract_package_manager(base_path: &Path) -> Option<String> {
+    let content = fs::read_to_string(base_path.join("package.json")).ok()?;
+    let json: Value = serde_json::from_str(&content).ok()?;
+    json["packageManager"]
+        .as_str()
+        .filter(|pm| !pm.is_empty())
+        .map(str::to_string)
+}
+
 /// Extract scripts, dependencies, and devDependencies from package.json
 /// Converts catalog-matching packages to "catalog:" references
 pub fn extract_package_info(dir: &Path, catalog: &IndexMap<String, String>) -> PackageInfo {
@@ -6,6 +6,9 @@ use std::path::Path;
 
 use super::types::{ComposeLocation, DetectedCompose};
 
+/// Service names that conventionally run package scripts, in preference order
+const EXEC_SERVICE_NAMES: &[&str] = &["workspace", "node", "app", "dev"];
+
 /// Find docker-compose files in the workspace
 pub fn find_compose_files() -> Result<Vec<DetectedCompose>> {
     let mut files = Vec::new();
@@ -69,3 +72,29 @@ pub fn find_compose_files() -> Result<Vec<DetectedCompose>> {
 
     Ok(files)
 }
+
+/// Detect the service that runs package scripts in the root/workspace compose file
+pub fn detect_compose_service(compose_files: &[DetectedCompose]) -> Option<String> {
+    let compose = compose_files.iter().find(|c| {
+        c.location == ComposeLocation::Workspace || c.location == ComposeLocation::Root
+    })?;
+    let content = fs::read_to_string(&compose.path).ok()?;
+    pick_compose_service(&content)
+}
+
+/// Pick the script-running service from compose YAML.
+///
+/// Prefers a conventionally named service (`workspace`, `node`, ...), then the
+/// first service in the file.
+pub fn pick_compose_service(content: &str) -> Option<String> {
+    let yaml: serde_yaml_ng::Value = serde_yaml_ng::from_str(content).ok()?;
+    let services = yaml.get("services")?.as_mapping()?;
+    let names: Vec<&str> = services.keys().filter_map(|k| k.as_str()).collect();
+
+    EXEC_SERVICE_NAMES
+        .iter()
+        .find(|preferred| names.contains(preferred))
+        .copied()
+        .or_else(|| names.first().copied())
+        .map(str::to_string)
+}
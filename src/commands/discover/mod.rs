@@ -30,6 +30,7 @@ pub use scanning::discover_from_workspaces;
 use crate::manifest::Manifest;
 use anyhow::Result;
 use colored::Colorize;
+use std::path::Path;
 
 /// Run project discovery
 pub fn run() -> Result<DiscoveryResult> {
@@ -41,12 +42,16 @@ pub fn run() -> Result<DiscoveryResult> {
     let apps = scanning::scan_apps(&catalog)?;
     let libs = scanning::scan_libs(&catalog)?;
     let compose_files = compose::find_compose_files()?;
+    let package_manager = catalog::extract_package_manager(Path::new("."));
+    let compose_service = compose::detect_compose_service(&compose_files);
 
     let result = DiscoveryResult {
         apps,
         libs,
         compose_files,
         catalog,
+        package_manager,
+        compose_service,
     };
 
     display::print_discovery_result(&result);
@@ -100,6 +105,7 @@ pub fn propose_manifest(discovery: &DiscoveryResult) -> Result<String> {
         supabase: None,
         traefik: None,
         restart: None,
+        profiles: vec![],
     });
 
     // Generate the TOML string
@@ -4,7 +4,8 @@ use indexmap::IndexMap;
 use std::fs;
 use tempfile::tempdir;
 
-use super::catalog::{extract_catalog_from_path, extract_package_info};
+use super::catalog::{extract_catalog_from_path, extract_package_info, extract_package_manager};
+use super::compose::pick_compose_service;
 use super::detection::{detect_framework, get_package_name};
 use super::scanning::discover_from_workspaces;
 use super::types::Framework;
@@ -107,6 +108,33 @@ fn test_extract_catalog() {
     assert!(!catalog.contains_key("@workspace/internal"));
 }
 
+#[test]
+fn test_extract_package_manager() {
+    let dir = tempdir().unwrap();
+    assert_eq!(extract_package_manager(dir.path()), None);
+
+    fs::write(
+        dir.path().join("package.json"),
+        r#"{"name": "root", "packageManager": "bun@1.1.38"}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        extract_package_manager(dir.path()).as_deref(),
+        Some("bun@1.1.38")
+    );
+}
+
+#[test]
+fn test_pick_compose_service() {
+    let preferred = "services:\n  postgres:\n    image: postgres:16\n  node:\n    image: node:22\n";
+    assert_eq!(pick_compose_service(preferred).as_deref(), Some("node"));
+
+    let first = "services:\n  api:\n    build: .\n  db:\n    image: postgres:16\n";
+    assert_eq!(pick_compose_service(first).as_deref(), Some("api"));
+
+    assert_eq!(pick_compose_service("volumes: {}\n"), None);
+}
+
 #[test]
 fn test_extract_package_info_basic() {
     let dir = tempdir().unwrap();
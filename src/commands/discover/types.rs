@@ -109,6 +109,12 @@ pub struct DiscoveryResult {
     pub libs: Vec<DetectedLib>,
     pub compose_files: Vec<DetectedCompose>,
     pub catalog: IndexMap<String, String>,
+    /// Root package.json `packageManager` (e.g., "bun@1.1.0")
+    #[serde(default)]
+    pub package_manager: Option<String>,
+    /// Service in the root/workspace compose file that runs package scripts
+    #[serde(default)]
+    pub compose_service: Option<String>,
 }
 
 impl DiscoveryResult {
@@ -3,6 +3,7 @@ use colored::Colorize;
 use std::fs;
 use std::path::Path;
 
+use crate::error::AirisError;
 use crate::manifest::{DocsMode, DocsVendor, MANIFEST_FILE, Manifest, MockPolicy, TestingSection};
 
 const DEFAULT_SOURCE_FILES: &[&str] = &[
@@ -12,6 +13,18 @@ const DEFAULT_SOURCE_FILES: &[&str] = &[
     "docs/ai/STACK.md",
 ];
 
+/// Every adapter file `docs wrap` / `docs sync` can generate
+const SUPPORTED_TARGETS: &[&str] = &[
+    "AGENTS.md",
+    "CLAUDE.md",
+    "GEMINI.md",
+    ".cursorrules",
+    ".windsurfrules",
+    "CONVENTIONS.md",
+    ".aider.conf.yml",
+    ".continuerc.json",
+];
+
 const DEFAULT_SKILLS_SOURCE: &str = "docs/ai/playbooks";
 const DEFAULT_HOOKS_POLICY: &str = "docs/ai/hooks/HOOKS_POLICY.md";
 
@@ -110,6 +123,19 @@ pub fn list() -> Result<()> {
         }
     }
 
+    let available: Vec<&str> = SUPPORTED_TARGETS
+        .iter()
+        .copied()
+        .filter(|t| !targets.iter().any(|configured| configured == t))
+        .collect();
+    if !available.is_empty() {
+        println!();
+        println!(
+            "Available (add with `airis workspace docs wrap <file>`): {}",
+            available.join(", ").dimmed()
+        );
+    }
+
     if let Some(skills_source) = effective_skills_source(&manifest) {
         println!();
         println!(
@@ -140,11 +166,7 @@ pub fn list() -> Result<()> {
 
 fn ensure_manifest_exists(manifest_path: &Path) -> Result<()> {
     if !manifest_path.exists() {
-        bail!(
-            "❌ {} not found. Create one (see docs/manifest.md) or ask Claude Code via {}.",
-            MANIFEST_FILE.bold(),
-            "/airis:init".bold()
-        );
+        return Err(AirisError::ManifestMissing.into());
     }
     Ok(())
 }
@@ -218,15 +240,63 @@ fn effective_hooks_policy(manifest: &Manifest) -> Option<String> {
     })
 }
 
+/// Write an adapter file.
+///
+/// Text and YAML adapters are wrapped in a managed block. When the file
+/// already has one, only the block is replaced and anything outside it
+/// (hand-written notes) is kept, so re-wrapping is idempotent. Files without
+/// a block, and JSON adapters (no comment syntax), go through the
+/// `[docs.mode]` overwrite policy instead.
 fn write_adapter_target(manifest: &Manifest, target: &str, force: bool) -> Result<()> {
     let target_path = Path::new(target);
-    handle_existing_file(manifest, target_path, force)?;
-
     let content = render_adapter(manifest, target)?;
-    fs::write(target_path, content).with_context(|| format!("Failed to write {}", target))?;
+
+    let output = match block_markers(target) {
+        Some((begin, end)) => {
+            let block = format!("{}\n{}\n{}", begin, content.trim_end(), end);
+            let existing = fs::read_to_string(target_path).ok();
+            match existing.and_then(|e| replace_managed_block(&e, begin, end, &block)) {
+                Some(updated) => updated,
+                None => {
+                    handle_existing_file(manifest, target_path, force)?;
+                    format!("{}\n", block)
+                }
+            }
+        }
+        None => {
+            handle_existing_file(manifest, target_path, force)?;
+            content
+        }
+    };
+
+    fs::write(target_path, output).with_context(|| format!("Failed to write {}", target))?;
     Ok(())
 }
 
+/// Begin/end markers of the managed block, in the target's comment syntax
+fn block_markers(target: &str) -> Option<(&'static str, &'static str)> {
+    if target.ends_with(".json") {
+        None
+    } else if target.ends_with(".yml") || target.ends_with(".yaml") {
+        Some(("# BEGIN airis", "# END airis"))
+    } else {
+        Some(("<!-- BEGIN airis -->", "<!-- END airis -->"))
+    }
+}
+
+/// Replace an existing managed block, keeping everything outside it.
+/// Returns `None` when `existing` has no complete block.
+fn replace_managed_block(existing: &str, begin: &str, end: &str, block: &str) -> Option<String> {
+    let start = existing.find(begin)?;
+    let end_idx = start + existing[start..].find(end)? + end.len();
+    Some(format!(
+        "{}{}{}",
+        &existing[..start],
+        block,
+        &existing[end_idx..]
+    ))
+}
+
 fn handle_existing_file(manifest: &Manifest, target_path: &Path, force: bool) -> Result<()> {
     if !target_path.exists() {
         return Ok(());
@@ -291,15 +361,30 @@ fn render_adapter(manifest: &Manifest, target: &str) -> Result<String> {
             hooks_policy.as_deref(),
             testing,
         )),
-        ".cursorrules" => Ok(render_cursorrules(
+        ".cursorrules" | ".windsurfrules" => Ok(render_rules_file(
+            target,
             &sources,
             skills_source.as_deref(),
             hooks_policy.as_deref(),
             testing,
         )),
+        "CONVENTIONS.md" => Ok(render_conventions_md(
+            &sources,
+            skills_source.as_deref(),
+            hooks_policy.as_deref(),
+            testing,
+        )),
+        ".aider.conf.yml" => Ok(render_aider_conf(&sources)),
+        ".continuerc.json" => render_continuerc(
+            &sources,
+            skills_source.as_deref(),
+            hooks_policy.as_deref(),
+            testing,
+        ),
         _ => bail!(
-            "❌ Unknown documentation file: {}. Supported: CLAUDE.md, .cursorrules, GEMINI.md, AGENTS.md",
-            target.red()
+            "❌ Unknown documentation file: {}. Supported: {}",
+            target.red(),
+            SUPPORTED_TARGETS.join(", ")
         ),
     }
 }
@@ -444,14 +529,16 @@ fn render_gemini_md(
     lines.join("\n")
 }
 
-fn render_cursorrules(
+/// Plain-text rules files (`.cursorrules`, `.windsurfrules`)
+fn render_rules_file(
+    target: &str,
     sources: &[String],
     skills_source: Option<&str>,
     hooks_policy: Option<&str>,
     testing: &TestingSection,
 ) -> String {
     let mut lines = vec![
-        "# .cursorrules".to_string(),
+        format!("# {}", target),
         "".to_string(),
         "This file is generated by `airis docs sync`.".to_string(),
         "".to_string(),
@@ -480,6 +567,94 @@ fn render_cursorrules(
     lines.join("\n")
 }
 
+/// Aider conventions file, loaded through `.aider.conf.yml`
+fn render_conventions_md(
+    sources: &[String],
+    skills_source: Option<&str>,
+    hooks_policy: Option<&str>,
+    testing: &TestingSection,
+) -> String {
+    let mut lines = vec![
+        "# CONVENTIONS.md".to_string(),
+        "".to_string(),
+        "<!-- Generated by `airis docs sync`. Do not edit it directly. -->".to_string(),
+        "".to_string(),
+        "Project conventions live in shared documentation:".to_string(),
+    ];
+    lines.extend(sources.iter().map(|source| format!("- `{}`", source)));
+    if let Some(skills_source) = skills_source {
+        lines.push(format!("- Task playbooks: `{}`", skills_source));
+    }
+    if let Some(hooks_policy) = hooks_policy {
+        lines.push(format!("- Hook policy: `{}`", hooks_policy));
+    }
+
+    let testing_lines = render_testing_policy(testing);
+    if !testing_lines.is_empty() {
+        lines.push("".to_string());
+        lines.push("Testing policy:".to_string());
+        lines.extend(testing_lines);
+    }
+
+    lines.push("".to_string());
+    lines.push(
+        "`manifest.toml` is the machine-readable source of truth for convention generation and, for containerized repos, Docker environment configuration."
+            .to_string(),
+    );
+    lines.push("".to_string());
+    lines.join("\n")
+}
+
+/// Aider config: YAML comments carry the wrapper, `read` loads the docs
+fn render_aider_conf(sources: &[String]) -> String {
+    let mut lines = vec![
+        "# .aider.conf.yml".to_string(),
+        "# Generated by `airis docs sync`. Do not edit it directly.".to_string(),
+        "# `manifest.toml` is the source of truth; edit [docs] there and re-run sync.".to_string(),
+        "read:".to_string(),
+        "  - CONVENTIONS.md".to_string(),
+    ];
+    lines.extend(
+        sources
+            .iter()
+            .map(|source| format!("  - {}", serde_json::Value::String(source.clone()))),
+    );
+    lines.push("".to_string());
+    lines.join("\n")
+}
+
+/// Continue config. JSON has no comments, so the wrapper is a pointer field.
+fn render_continuerc(
+    sources: &[String],
+    skills_source: Option<&str>,
+    hooks_policy: Option<&str>,
+    testing: &TestingSection,
+) -> Result<String> {
+    let mut message = vec!["Read the shared project instructions first:".to_string()];
+    message.extend(sources.iter().map(|source| format!("- {}", source)));
+    if let Some(skills_source) = skills_source {
+        message.push(format!("- Task playbooks: {}", skills_source));
+    }
+    if let Some(hooks_policy) = hooks_policy {
+        message.push(format!("- Hook policy: {}", hooks_policy));
+    }
+    let testing_lines = render_testing_policy(testing);
+    if !testing_lines.is_empty() {
+        message.push("Testing policy:".to_string());
+        message.extend(testing_lines);
+    }
+
+    let value = serde_json::json!({
+        "_generated": "Generated by `airis docs sync` from manifest.toml [docs]. Do not edit it directly.",
+        "mergeBehavior": "merge",
+        "systemMessage": message.join("\n"),
+    });
+    let mut content =
+        serde_json::to_string_pretty(&value).context("Failed to serialize .continuerc.json")?;
+    content.push('\n');
+    Ok(content)
+}
+
 fn render_testing_policy(testing: &TestingSection) -> Vec<String> {
     // If testing section is all defaults with no custom rules, emit nothing (backwards-compatible)
     if testing.mock_policy == MockPolicy::Allowed
@@ -541,6 +716,9 @@ fn vendor_for_target(target: &str) -> Result<DocsVendor> {
         "AGENTS.md" | ".cursorrules" => Ok(DocsVendor::Codex),
         "CLAUDE.md" => Ok(DocsVendor::Claude),
         "GEMINI.md" => Ok(DocsVendor::Gemini),
+        ".windsurfrules" => Ok(DocsVendor::Windsurf),
+        "CONVENTIONS.md" | ".aider.conf.yml" => Ok(DocsVendor::Aider),
+        ".continuerc.json" => Ok(DocsVendor::Continue),
         _ => bail!("Unsupported target: {}", target),
     }
 }
@@ -550,6 +728,9 @@ fn targets_for_vendor(vendor: &DocsVendor) -> &'static [&'static str] {
         DocsVendor::Codex => &["AGENTS.md"],
         DocsVendor::Claude => &["CLAUDE.md"],
         DocsVendor::Gemini => &["GEMINI.md"],
+        DocsVendor::Windsurf => &[".windsurfrules"],
+        DocsVendor::Aider => &["CONVENTIONS.md", ".aider.conf.yml"],
+        DocsVendor::Continue => &[".continuerc.json"],
     }
 }
 
@@ -888,4 +1069,133 @@ to = "main"
         handle_existing_file(&manifest, &target, false).unwrap();
         assert!(dir.path().join("CLAUDE.md.bak").exists());
     }
+
+    #[test]
+    fn render_windsurfrules_points_to_manifest() {
+        let manifest = manifest_with_docs();
+        let rendered = render_adapter(&manifest, ".windsurfrules").unwrap();
+
+        assert!(rendered.starts_with("# .windsurfrules"));
+        assert!(rendered.contains("- `docs/ai/PROJECT_RULES.md`"));
+        assert!(rendered.contains("`manifest.toml`"));
+    }
+
+    #[test]
+    fn render_aider_conf_reads_conventions_and_sources() {
+        let manifest = manifest_with_docs();
+        let rendered = render_adapter(&manifest, ".aider.conf.yml").unwrap();
+
+        let yaml: serde_yaml_ng::Value = serde_yaml_ng::from_str(&rendered).unwrap();
+        let read: Vec<&str> = yaml["read"]
+            .as_sequence()
+            .unwrap()
+            .iter()
+            .filter_map(|v| v.as_str())
+            .collect();
+        assert_eq!(read[0], "CONVENTIONS.md");
+        assert!(read.contains(&"docs/ai/PROJECT_RULES.md"));
+        assert!(rendered.contains("# `manifest.toml` is the source of truth"));
+    }
+
+    #[test]
+    fn render_continuerc_is_valid_json_with_pointer_field() {
+        let manifest = manifest_with_docs();
+        let rendered = render_adapter(&manifest, ".continuerc.json").unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&rendered).unwrap();
+        assert!(
+            json["_generated"]
+                .as_str()
+                .unwrap()
+                .contains("manifest.toml")
+        );
+        assert!(
+            json["systemMessage"]
+                .as_str()
+                .unwrap()
+                .contains("docs/ai/PROJECT_RULES.md")
+        );
+    }
+
+    #[test]
+    fn new_targets_map_to_vendors() {
+        assert_eq!(
+            vendor_for_target(".windsurfrules").unwrap(),
+            DocsVendor::Windsurf
+        );
+        assert_eq!(
+            vendor_for_target("CONVENTIONS.md").unwrap(),
+            DocsVendor::Aider
+        );
+        assert_eq!(
+            vendor_for_target(".continuerc.json").unwrap(),
+            DocsVendor::Continue
+        );
+        // Every vendor target must be renderable
+        let manifest = manifest_with_docs();
+        for vendor in [
+            DocsVendor::Windsurf,
+            DocsVendor::Aider,
+            DocsVendor::Continue,
+        ] {
+            for target in targets_for_vendor(&vendor) {
+                assert!(SUPPORTED_TARGETS.contains(target));
+                assert!(render_adapter(&manifest, target).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn rewrap_preserves_manual_sections() {
+        let _guard = DIR_LOCK.lock().unwrap();
+        let original_dir = std::env::current_dir().unwrap();
+        let dir = tempdir().unwrap();
+        std::env::set_current_dir(dir.path()).unwrap();
+
+        let result = std::panic::catch_unwind(|| {
+            let mut manifest = manifest_with_docs();
+            manifest.docs.mode = DocsMode::Warn;
+
+            write_adapter_target(&manifest, "CLAUDE.md", false).unwrap();
+            let first = fs::read_to_string("CLAUDE.md").unwrap();
+            assert!(first.starts_with("<!-- BEGIN airis -->\n# CLAUDE.md"));
+            assert!(first.ends_with("<!-- END airis -->\n"));
+
+            // Hand-written notes above and below the block
+            let edited = format!(
+                "# Team notes\n\n{}\n## Local quirks\n- use port 4000\n",
+                first
+            );
+            fs::write("CLAUDE.md", &edited).unwrap();
+
+            // Warn mode doesn't refuse: only the managed block is rewritten
+            write_adapter_target(&manifest, "CLAUDE.md", false).unwrap();
+            let second = fs::read_to_string("CLAUDE.md").unwrap();
+            assert_eq!(second, edited);
+
+            // Idempotent
+            write_adapter_target(&manifest, "CLAUDE.md", false).unwrap();
+            assert_eq!(fs::read_to_string("CLAUDE.md").unwrap(), second);
+        });
+
+        std::env::set_current_dir(original_dir).unwrap();
+        result.unwrap();
+    }
+
+    #[test]
+    fn replace_managed_block_updates_only_the_block() {
+        let existing = "before\n# BEGIN airis\nold\n# END airis\nafter\n";
+        let updated = replace_managed_block(
+            existing,
+            "# BEGIN airis",
+            "# END airis",
+            "# BEGIN airis\nnew\n# END airis",
+        )
+        .unwrap();
+        assert_eq!(updated, "before\n# BEGIN airis\nnew\n# END airis\nafter\n");
+
+        assert!(replace_managed_block("no block", "# BEGIN airis", "# END airis", "x").is_none());
+        assert_eq!(block_markers(".continuerc.json"), None);
+        assert_eq!(block_markers(".aider.conf.yml").unwrap().0, "# BEGIN airis");
+    }
 }
@@ -11,10 +11,15 @@ use std::fs;
 use std::path::Path;
 
 use crate::commands::manifest_cmd::WorkspaceTruth;
+use crate::error::AirisError;
 use crate::manifest::{MANIFEST_FILE, Manifest};
-use crate::ownership::{Ownership, get_ownership};
+use crate::ownership::{Ownership, OwnershipRules, get_ownership};
+use crate::safe_fs::{SafeAction, SafeFS};
 use crate::templates::TemplateEngine;
 
+/// Maximum number of JSON differences listed per drifted file
+const MAX_LISTED_DIFFERENCES: usize = 5;
+
 /// Issue severity levels
 #[derive(Debug, Clone, PartialEq)]
 pub enum Severity {
@@ -37,11 +42,7 @@ pub struct Issue {
 pub fn run_truth(json_output: bool) -> Result<()> {
     let manifest_path = Path::new(MANIFEST_FILE);
     if !manifest_path.exists() {
-        anyhow::bail!(
-            "manifest.toml not found.\n\n\
-             Hint: Create one (see docs/manifest.md) or ask Claude Code via /airis:init.\n\
-             This command requires an airis workspace."
-        );
+        return Err(AirisError::ManifestMissing.into());
     }
 
     let manifest = Manifest::load(manifest_path).context("Failed to load manifest.toml")?;
@@ -83,15 +84,13 @@ pub fn run_truth(json_output: bool) -> Result<()> {
 
 /// Run the doctor command
 pub fn run(fix: bool) -> Result<()> {
-    println!("{}", "🔍 Diagnosing workspace health...".bright_blue());
-    println!();
+    crate::progress!("{}", "🔍 Diagnosing workspace health...".bright_blue());
+    crate::progress!();
 
     // Check if manifest.toml exists
     let manifest_path = Path::new(MANIFEST_FILE);
     if !manifest_path.exists() {
-        println!("{}", "❌ manifest.toml not found".red());
-        println!("   Create one (see docs/manifest.md) or ask Claude Code via /airis:init.");
-        return Ok(());
+        return Err(AirisError::ManifestMissing.into());
     }
 
     // Load manifest
@@ -100,14 +99,16 @@ pub fn run(fix: bool) -> Result<()> {
     // Collect issues
     let mut issues: Vec<Issue> = Vec::new();
 
-    // Check each generated file
-    check_generated_files(&manifest, &mut issues)?;
+    // Check each generated file (keeps the expected content of drifted ones)
+    let drifted = check_generated_files(&manifest, &mut issues)?;
 
     // Check for orphaned packages (not in manifest)
     check_orphaned_packages(&manifest, &mut issues)?;
 
     // Check for leaked host artifacts (node_modules, .pnpm, build outputs, etc.)
-    check_host_artifacts(&mut issues)?;
+    if manifest.is_docker_first() {
+        check_host_artifacts(Path::new("."), &mut issues);
+    }
 
     // Report results
     if issues.is_empty() {
@@ -129,27 +130,29 @@ pub fn run(fix: bool) -> Result<()> {
 
     if fix {
         // Auto-fix detected issues
-        println!("{}", "🔧 Healing workspace...".bright_blue());
-        println!();
+        crate::progress!("{}", "🔧 Healing workspace...".bright_blue());
+        crate::progress!();
+
+        // 1. Rewrite drifted generated files (previous content is backed up)
+        let safe_fs = SafeFS::current(false)?;
+        for (file, expected) in &drifted {
+            crate::progress!("   {} Regenerating {}...", "→".dimmed(), file);
+            safe_fs.write(file, expected)?;
+        }
 
-        // 1. Regenerate files
+        // 2. Regenerate remaining files
         crate::commands::generate::sync_from_manifest(&manifest)?;
 
-        // 2. Remove host artifacts (physical enforcement)
+        // 3. Remove host artifacts (physical enforcement)
         for issue in &issues {
             if issue.description.contains("leaked from container") {
-                let path = Path::new(&issue.file);
-                if path.exists() {
-                    println!(
-                        "   {} Removing host artifact: {}...",
-                        "→".dimmed(),
-                        issue.file
-                    );
-                    if path.is_dir() {
-                        let _ = fs::remove_dir_all(path);
-                    } else {
-                        let _ = fs::remove_file(path);
-                    }
+                println!(
+                    "   {} Removing host artifact: {}...",
+                    "→".dimmed(),
+                    issue.file
+                );
+                if let SafeAction::Skipped(reason) = safe_fs.clean_artifact(&issue.file)?.action {
+                    println!("   {} {} ({})", "⏭️".yellow(), issue.file, reason);
                 }
             }
         }
@@ -167,33 +170,78 @@ pub fn run(fix: bool) -> Result<()> {
     Ok(())
 }
 
-/// Check all generated files for drift
-fn check_generated_files(manifest: &Manifest, issues: &mut Vec<Issue>) -> Result<()> {
+/// Check all generated files for drift.
+///
+/// Each file is re-rendered in memory and compared against disk. Returns the
+/// (path, expected content) of every drifted or missing file so `--fix` can
+/// rewrite them.
+fn check_generated_files(
+    manifest: &Manifest,
+    issues: &mut Vec<Issue>,
+) -> Result<Vec<(String, String)>> {
     let engine = TemplateEngine::new()?;
+    let rules = OwnershipRules::load(Path::new("."))?;
 
     let resolved_catalog = crate::pnpm::read_workspace_catalog();
 
+    let mut drifted = Vec::new();
+
     // Check package.json
     check_file(
         "package.json",
         || engine.render_package_json(manifest, &resolved_catalog),
+        &rules,
         issues,
+        &mut drifted,
     )?;
 
+    // Check root tsconfig files (same inputs as `airis gen`)
+    if manifest.has_workspace() && !manifest.typescript.skip {
+        check_file(
+            "tsconfig.base.json",
+            || engine.render_tsconfig_base(manifest),
+            &rules,
+            issues,
+            &mut drifted,
+        )?;
+
+        check_file(
+            "tsconfig.json",
+            || {
+                let workspace_root =
+                    std::env::current_dir().context("Failed to get current directory")?;
+                let path_entries =
+                    crate::commands::generate::collect_path_entries(manifest, &workspace_root)?;
+                let ts_major =
+                    crate::commands::generate::detect_ts_major(manifest, &resolved_catalog);
+                engine.render_tsconfig_root(manifest, &path_entries, ts_major)
+            },
+            &rules,
+            issues,
+            &mut drifted,
+        )?;
+    }
+
     // pnpm-workspace.yaml is user-owned — not checked by airis doctor
 
     // compose.yml and CI/CD workflows are project-owned — not checked by airis doctor
 
-    Ok(())
+    Ok(drifted)
 }
 
 /// Check a single file for drift
-fn check_file<F>(filename: &str, generate: F, issues: &mut Vec<Issue>) -> Result<()>
+fn check_file<F>(
+    filename: &str,
+    generate: F,
+    rules: &OwnershipRules,
+    issues: &mut Vec<Issue>,
+    drifted: &mut Vec<(String, String)>,
+) -> Result<()>
 where
     F: FnOnce() -> Result<String>,
 {
     let path = Path::new(filename);
-    let ownership = get_ownership(path);
+    let ownership = get_ownership(path, rules);
 
     if !path.exists() {
         // Only report missing for tool-owned files
@@ -203,6 +251,7 @@ where
                 description: "Missing (will be created)".to_string(),
                 severity: Severity::Error,
             });
+            drifted.push((filename.to_string(), generate()?));
         }
         return Ok(());
     }
@@ -219,26 +268,7 @@ where
     let expected_normalized = expected.replace("\r\n", "\n");
 
     if current_normalized != expected_normalized {
-        // Count differences for more helpful message
-        let current_lines: Vec<&str> = current_normalized.lines().collect();
-        let expected_lines: Vec<&str> = expected_normalized.lines().collect();
-
-        let diff_count = current_lines
-            .iter()
-            .zip(expected_lines.iter())
-            .filter(|(a, b)| a != b)
-            .count();
-
-        let line_diff = (current_lines.len() as i32 - expected_lines.len() as i32).abs();
-
-        let description = if line_diff > 0 {
-            format!(
-                "Content mismatch ({} lines differ, {} lines added/removed)",
-                diff_count, line_diff
-            )
-        } else {
-            format!("Content mismatch ({} lines differ)", diff_count.max(1))
-        };
+        let description = describe_drift(&current_normalized, &expected_normalized);
 
         // Severity depends on ownership
         let severity = match ownership {
@@ -246,6 +276,11 @@ where
             Ownership::User => Severity::Warning, // User files are their responsibility
         };
 
+        // Only tool-owned files are rewritten by --fix
+        if matches!(ownership, Ownership::Tool) {
+            drifted.push((filename.to_string(), expected));
+        }
+
         issues.push(Issue {
             file: filename.to_string(),
             description,
@@ -256,6 +291,109 @@ where
     Ok(())
 }
 
+/// Describe how a file differs from its generated content.
+///
+/// JSON files list the differing keys (e.g. `scripts.dev`); anything else,
+/// or JSON that only differs in formatting, falls back to line counts.
+fn describe_drift(current: &str, expected: &str) -> String {
+    if let (Ok(current_json), Ok(expected_json)) = (
+        serde_json::from_str::<serde_json::Value>(current),
+        serde_json::from_str::<serde_json::Value>(expected),
+    ) {
+        let differences = compare_json_content(&current_json, &expected_json);
+        if !differences.is_empty() {
+            let mut listed = differences
+                .iter()
+                .take(MAX_LISTED_DIFFERENCES)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join("; ");
+            if differences.len() > MAX_LISTED_DIFFERENCES {
+                listed.push_str(&format!(
+                    "; and {} more",
+                    differences.len() - MAX_LISTED_DIFFERENCES
+                ));
+            }
+            return format!("Out of sync with manifest.toml ({})", listed);
+        }
+    }
+
+    // Count differences for more helpful message
+    let current_lines: Vec<&str> = current.lines().collect();
+    let expected_lines: Vec<&str> = expected.lines().collect();
+
+    let diff_count = current_lines
+        .iter()
+        .zip(expected_lines.iter())
+        .filter(|(a, b)| a != b)
+        .count();
+
+    let line_diff = (current_lines.len() as i32 - expected_lines.len() as i32).abs();
+
+    if line_diff > 0 {
+        format!(
+            "Content mismatch ({} lines differ, {} lines added/removed)",
+            diff_count, line_diff
+        )
+    } else {
+        format!("Content mismatch ({} lines differ)", diff_count.max(1))
+    }
+}
+
+/// List key-level differences between on-disk and generated JSON.
+///
+/// Objects are compared recursively and reported by dotted path; arrays and
+/// scalars are compared as whole values.
+fn compare_json_content(current: &serde_json::Value, expected: &serde_json::Value) -> Vec<String> {
+    let mut differences = Vec::new();
+    compare_json_at("", current, expected, &mut differences);
+    differences
+}
+
+fn compare_json_at(
+    prefix: &str,
+    current: &serde_json::Value,
+    expected: &serde_json::Value,
+    differences: &mut Vec<String>,
+) {
+    use serde_json::Value;
+
+    let key_path = |key: &str| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", prefix, key)
+        }
+    };
+
+    match (current, expected) {
+        (Value::Object(current_map), Value::Object(expected_map)) => {
+            for (key, expected_value) in expected_map {
+                match current_map.get(key) {
+                    Some(current_value) => {
+                        compare_json_at(&key_path(key), current_value, expected_value, differences)
+                    }
+                    None => differences.push(format!(
+                        "{} missing (expected {})",
+                        key_path(key),
+                        expected_value
+                    )),
+                }
+            }
+            for key in current_map.keys() {
+                if !expected_map.contains_key(key) {
+                    differences.push(format!("{} not in manifest.toml", key_path(key)));
+                }
+            }
+        }
+        _ if current != expected => {
+            let label = if prefix.is_empty() { "(root)" } else { prefix };
+            differences.push(format!("{}: {} → {}", label, current, expected));
+        }
+        _ => {}
+    }
+}
+
 /// Check for orphaned packages (exist on disk but not in manifest)
 fn check_orphaned_packages(manifest: &Manifest, issues: &mut Vec<Issue>) -> Result<()> {
     // Get declared apps from manifest.apps keys
@@ -326,8 +464,9 @@ fn artifact_severity(name: &str) -> Severity {
 /// Check for host artifacts that should only exist inside containers.
 ///
 /// In Docker-first mode, dependencies and build outputs should stay in
-/// container volumes. If they appear on the host, the bind mount is leaking.
-fn check_host_artifacts(issues: &mut Vec<Issue>) -> Result<()> {
+/// container volumes. If they appear on the host, the bind mount is leaking
+/// or someone ran `pnpm install` on the host.
+fn check_host_artifacts(root: &Path, issues: &mut Vec<Issue>) {
     let artifact_names = [
         "node_modules",
         ".pnpm",
@@ -341,30 +480,30 @@ fn check_host_artifacts(issues: &mut Vec<Issue>) -> Result<()> {
 
     use walkdir::WalkDir;
 
-    for entry in WalkDir::new(".")
-        .max_depth(5)
-        .into_iter()
-        .filter_entry(|e| {
-            let name = e.file_name().to_string_lossy();
-            // Skip .git and nested node_modules to keep it fast
-            name != ".git" && (name != "node_modules" || e.depth() == 0)
-        })
-        .filter_map(|e| e.ok())
-    {
-        let path = entry.path();
-        if !path.is_dir() {
+    let mut walker = WalkDir::new(root).max_depth(5).into_iter();
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if !entry.file_type().is_dir() || entry.depth() == 0 {
             continue;
         }
 
         let name = entry.file_name().to_string_lossy();
+        // Skip .git, and never descend into an artifact (keeps it fast)
+        if name == ".git" {
+            walker.skip_current_dir();
+            continue;
+        }
         if artifact_names.contains(&name.as_ref()) {
+            walker.skip_current_dir();
+            let path = entry.path();
             let path_str = path.to_string_lossy();
 
-            // Skip root-level matches for artifacts that might legitimately exist at root
-            // (e.g., ./node_modules is the root volume mount)
-            if (path_str == "./node_modules" || path_str == "node_modules")
-                && name == "node_modules"
-            {
+            // Generated compose mounts named volumes over node_modules, dist,
+            // build, ... for every app: Docker leaves empty mount points on
+            // the host, so only a populated directory is a leak
+            if is_empty_dir(path) {
                 continue;
             }
 
@@ -381,8 +520,10 @@ fn check_host_artifacts(issues: &mut Vec<Issue>) -> Result<()> {
             });
         }
     }
+}
 
-    Ok(())
+fn is_empty_dir(path: &Path) -> bool {
+    fs::read_dir(path).is_ok_and(|mut entries| entries.next().is_none())
 }
 
 #[cfg(test)]
@@ -414,4 +555,101 @@ mod tests {
         assert_eq!(artifact_severity("build"), Severity::Warning);
         assert_eq!(artifact_severity("coverage"), Severity::Warning);
     }
+
+    #[test]
+    fn test_host_artifacts_flag_installs_but_not_volume_mount_points() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("node_modules")).unwrap();
+        fs::create_dir_all(root.join("apps/web/node_modules/react")).unwrap();
+        fs::write(root.join("apps/web/node_modules/react/package.json"), "{}").unwrap();
+        fs::create_dir_all(root.join("libs/ui/dist")).unwrap();
+        fs::write(root.join("libs/ui/dist/index.js"), "").unwrap();
+        // Empty per-app mount points left behind by compose named volumes
+        fs::create_dir_all(root.join("apps/api/node_modules")).unwrap();
+        fs::create_dir_all(root.join("apps/api/dist")).unwrap();
+        fs::create_dir_all(root.join("apps/web/.next")).unwrap();
+
+        let relative_issues = |issues: &[Issue]| {
+            let mut files: Vec<String> = issues
+                .iter()
+                .map(|i| {
+                    Path::new(&i.file)
+                        .strip_prefix(root)
+                        .unwrap()
+                        .to_string_lossy()
+                        .to_string()
+                })
+                .collect();
+            files.sort();
+            files
+        };
+
+        let mut issues = Vec::new();
+        check_host_artifacts(root, &mut issues);
+        assert_eq!(
+            relative_issues(&issues),
+            vec!["apps/web/node_modules", "libs/ui/dist"]
+        );
+
+        // A populated root node_modules means a host `pnpm install`
+        fs::create_dir_all(root.join("node_modules/.pnpm")).unwrap();
+        let mut issues = Vec::new();
+        check_host_artifacts(root, &mut issues);
+        assert!(relative_issues(&issues).contains(&"node_modules".to_string()));
+        assert!(!relative_issues(&issues).contains(&"apps/api/node_modules".to_string()));
+    }
+
+    #[test]
+    fn test_docker_first_follows_mode_or_workspace_service() {
+        let parse = |extra: &str| -> Manifest {
+            toml::from_str(&format!("version = 1\n[project]\nid = \"t\"\n{}", extra)).unwrap()
+        };
+        assert!(!parse("").is_docker_first());
+        assert!(parse("[docker.workspace]\nservice = \"workspace\"\n").is_docker_first());
+        assert!(
+            !parse("[docker]\nmode = \"hybrid\"\n[docker.workspace]\nservice = \"workspace\"\n")
+                .is_docker_first()
+        );
+        assert!(parse("[docker]\nmode = \"docker-first\"\n").is_docker_first());
+    }
+
+    #[test]
+    fn test_compare_json_content_reports_dependency_and_script_changes() {
+        let current = serde_json::json!({
+            "name": "demo",
+            "scripts": { "dev": "next dev", "lint": "eslint ." },
+            "dependencies": { "react": "^18.0.0" }
+        });
+        let expected = serde_json::json!({
+            "name": "demo",
+            "scripts": { "dev": "next dev --turbo" },
+            "dependencies": { "react": "^19.0.0", "next": "^15.0.0" }
+        });
+
+        let differences = compare_json_content(&current, &expected);
+        assert_eq!(
+            differences,
+            vec![
+                "scripts.dev: \"next dev\" → \"next dev --turbo\"",
+                "scripts.lint not in manifest.toml",
+                "dependencies.react: \"^18.0.0\" → \"^19.0.0\"",
+                "dependencies.next missing (expected \"^15.0.0\")",
+            ]
+        );
+        assert!(compare_json_content(&expected, &expected).is_empty());
+    }
+
+    #[test]
+    fn test_describe_drift_falls_back_to_line_counts() {
+        // Same JSON, different formatting
+        let description = describe_drift("{\"a\": 1}", "{\n  \"a\": 1\n}");
+        assert!(description.starts_with("Content mismatch"));
+
+        let description = describe_drift("{\"a\": 1}", "{\"a\": 2}");
+        assert_eq!(description, "Out of sync with manifest.toml (a: 1 → 2)");
+
+        let description = describe_drift("a\nb\n", "a\nc\n");
+        assert_eq!(description, "Content mismatch (1 lines differ)");
+    }
 }
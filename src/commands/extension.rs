@@ -0,0 +1,182 @@
+//! External subcommands: `airis workspace <name>` runs an `airis-<name>` executable
+//!
+//! Built-in commands always take precedence (clap matches them first). For
+//! anything else the executable is looked up in this order:
+//! 1. `.airis/bin/airis-<name>` in the workspace root
+//! 2. `airis-<name>` on `PATH`
+//!
+//! The extension receives the remaining arguments plus `AIRIS_ROOT` (the
+//! workspace root) and, when present, `AIRIS_MANIFEST` (its manifest.toml).
+
+use anyhow::{Context, Result, bail};
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::manifest::MANIFEST_FILE;
+
+/// File name prefix of extension executables
+pub const EXTENSION_PREFIX: &str = "airis-";
+
+/// Workspace-local extension directory, searched before `PATH`
+pub const LOCAL_BIN_DIR: &str = ".airis/bin";
+
+/// Run the extension named by `args[0]` with the remaining arguments.
+/// A failing extension's exit code is passed through.
+pub fn run(args: &[String]) -> Result<()> {
+    let Some((name, rest)) = args.split_first() else {
+        bail!("missing extension name");
+    };
+    let root = std::env::current_dir().context("Failed to get current directory")?;
+    let Some(program) = find_extension(&root, name, std::env::var_os("PATH")) else {
+        bail!(
+            "unknown command '{}': no {}{} in {} or on PATH",
+            name,
+            EXTENSION_PREFIX,
+            name,
+            LOCAL_BIN_DIR
+        );
+    };
+
+    let mut cmd = Command::new(&program);
+    cmd.args(rest).env("AIRIS_ROOT", &root);
+    let manifest = root.join(MANIFEST_FILE);
+    if manifest.exists() {
+        cmd.env("AIRIS_MANIFEST", manifest);
+    }
+
+    let status = cmd
+        .status()
+        .with_context(|| format!("Failed to run {}", program.display()))?;
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Resolve `airis-<name>`, preferring the workspace-local directory
+fn find_extension(root: &Path, name: &str, path: Option<OsString>) -> Option<PathBuf> {
+    if name.is_empty() || name.contains(['/', '\\']) {
+        return None;
+    }
+    let file = format!("{}{}", EXTENSION_PREFIX, name);
+    search_dirs(root, path)
+        .into_iter()
+        .map(|dir| dir.join(&file))
+        .find(|candidate| is_executable(candidate))
+}
+
+/// Extension names found in the lookup directories, sorted and deduplicated
+pub fn discover(root: &Path, path: Option<OsString>) -> Vec<String> {
+    let mut names: Vec<String> = search_dirs(root, path)
+        .into_iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .flatten()
+        .filter(|entry| is_executable(&entry.path()))
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            file_name
+                .strip_prefix(EXTENSION_PREFIX)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+        })
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Append discovered extensions to the top-level help. Built-in names are
+/// skipped, since an extension can never shadow them.
+pub fn with_extensions_help(cmd: clap::Command) -> clap::Command {
+    let Ok(root) = std::env::current_dir() else {
+        return cmd;
+    };
+    let builtins: Vec<&str> = cmd.get_subcommands().map(|sub| sub.get_name()).collect();
+    let names: Vec<String> = discover(&root, std::env::var_os("PATH"))
+        .into_iter()
+        .filter(|name| !builtins.contains(&name.as_str()))
+        .collect();
+    if names.is_empty() {
+        return cmd;
+    }
+
+    let mut help = cmd
+        .get_after_help()
+        .map(|h| format!("{}\n\n", h))
+        .unwrap_or_default();
+    help.push_str("EXTENSIONS:");
+    for name in &names {
+        help.push_str(&format!("\n  airis workspace {}", name));
+    }
+    cmd.after_help(help)
+}
+
+fn search_dirs(root: &Path, path: Option<OsString>) -> Vec<PathBuf> {
+    let mut dirs = vec![root.join(LOCAL_BIN_DIR)];
+    if let Some(path) = path {
+        dirs.extend(std::env::split_paths(&path));
+    }
+    dirs
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .is_ok_and(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn install(dir: &Path, file: &str) -> PathBuf {
+        fs::create_dir_all(dir).unwrap();
+        let path = dir.join(file);
+        fs::write(&path, "#!/bin/sh\n").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_local_bin_wins_over_path() {
+        let root = tempfile::tempdir().unwrap();
+        let global = tempfile::tempdir().unwrap();
+        let local = install(&root.path().join(LOCAL_BIN_DIR), "airis-deploy");
+        install(global.path(), "airis-deploy");
+        let on_path = install(global.path(), "airis-report");
+
+        let path = Some(global.path().as_os_str().to_owned());
+        assert_eq!(
+            find_extension(root.path(), "deploy", path.clone()),
+            Some(local)
+        );
+        assert_eq!(
+            find_extension(root.path(), "report", path.clone()),
+            Some(on_path)
+        );
+        assert_eq!(find_extension(root.path(), "missing", path.clone()), None);
+        assert_eq!(find_extension(root.path(), "../deploy", path), None);
+    }
+
+    #[test]
+    fn test_discover_lists_executables_only() {
+        let root = tempfile::tempdir().unwrap();
+        let bin = root.path().join(LOCAL_BIN_DIR);
+        install(&bin, "airis-deploy");
+        install(&bin, "airis-report");
+        fs::write(bin.join("airis-notes"), "not executable").unwrap();
+        fs::write(bin.join("README"), "").unwrap();
+
+        assert_eq!(discover(root.path(), None), vec!["deploy", "report"]);
+    }
+}
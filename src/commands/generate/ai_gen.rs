@@ -4,6 +4,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::manifest::Manifest;
+use crate::safe_fs::{SafeAction, SafeFS};
 
 const BEGIN_BLOCK: &str = "<!-- BEGIN GENERATED airis gen -->";
 const END_BLOCK: &str = "<!-- END GENERATED -->";
@@ -13,7 +14,11 @@ const END_BLOCK: &str = "<!-- END GENERATED -->";
 /// This implements the Single Source of Truth (SSOT) for AI rules as specified
 /// in IDEAL_STATE.md §5. It manages CLAUDE.md, AGENTS.md, GEMINI.md,
 /// and individual rule files for Cursor and Claude.
-pub fn sync_ai_rules(manifest: &Manifest, generated_paths: &mut Vec<String>) -> Result<()> {
+pub fn sync_ai_rules(
+    manifest: &Manifest,
+    safe_fs: &SafeFS,
+    generated_paths: &mut Vec<String>,
+) -> Result<()> {
     if manifest.ai.shared_rules.is_empty() {
         return Ok(());
     }
@@ -24,11 +29,13 @@ pub fn sync_ai_rules(manifest: &Manifest, generated_paths: &mut Vec<String>) ->
             &claude.target,
             &manifest.ai.shared_rules,
             "claude",
+            safe_fs,
             generated_paths,
         )?;
         sync_individual_rules(
             &claude.rules_dir,
             &manifest.ai.shared_rules,
+            safe_fs,
             generated_paths,
         )?;
     }
@@ -39,6 +46,7 @@ pub fn sync_ai_rules(manifest: &Manifest, generated_paths: &mut Vec<String>) ->
             &codex.target,
             &manifest.ai.shared_rules,
             "codex",
+            safe_fs,
             generated_paths,
         )?;
     }
@@ -49,6 +57,7 @@ pub fn sync_ai_rules(manifest: &Manifest, generated_paths: &mut Vec<String>) ->
             &gemini.target,
             &manifest.ai.shared_rules,
             "gemini",
+            safe_fs,
             generated_paths,
         )?;
     }
@@ -58,6 +67,7 @@ pub fn sync_ai_rules(manifest: &Manifest, generated_paths: &mut Vec<String>) ->
         sync_individual_rules(
             &cursor.rules_dir,
             &manifest.ai.shared_rules,
+            safe_fs,
             generated_paths,
         )?;
     }
@@ -84,6 +94,7 @@ fn generate_vendor_target(
     target_path_str: &str,
     sources: &[String],
     vendor: &str,
+    safe_fs: &SafeFS,
     generated_paths: &mut Vec<String>,
 ) -> Result<()> {
     let target_path = resolve_path(target_path_str)?;
@@ -97,14 +108,13 @@ fn generate_vendor_target(
         format!("{}\n{}\n{}\n", BEGIN_BLOCK, generated_content, END_BLOCK)
     };
 
-    if let Some(parent) = target_path.parent() {
-        fs::create_dir_all(parent)?;
-    }
-    fs::write(&target_path, full_content)
-        .with_context(|| format!("Failed to write {}", target_path.display()))?;
+    let unchanged = write_target(safe_fs, &target_path, full_content.as_bytes())?;
     generated_paths.push(target_path_str.to_string());
+    if unchanged {
+        return Ok(());
+    }
 
-    println!(
+    crate::progress!(
         "   {} Syncing {} ({} sources)",
         "→".dimmed(),
         target_path_str.cyan(),
@@ -114,6 +124,27 @@ fn generate_vendor_target(
     Ok(())
 }
 
+/// Write an AI target, returning whether it already held `content`.
+///
+/// Targets inside the workspace are edited through SafeFS regardless of
+/// ownership, since only the generated block is replaced. Targets under `~/`
+/// are outside SafeFS's bounds and are written directly.
+fn write_target(safe_fs: &SafeFS, path: &Path, content: &[u8]) -> Result<bool> {
+    if path.is_relative() || path.starts_with(safe_fs.root()) {
+        let result = safe_fs.edit(path, content)?;
+        return Ok(result.action == SafeAction::Unchanged);
+    }
+
+    if safe_fs.is_unchanged(path, content) {
+        return Ok(true);
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(false)
+}
+
 fn render_combined_sources(sources: &[String], _vendor: &str) -> Result<String> {
     let mut lines = Vec::new();
 
@@ -167,6 +198,7 @@ fn update_generated_block(existing: &str, new_gen: &str) -> Result<String> {
 fn sync_individual_rules(
     rules_dir_str: &str,
     sources: &[String],
+    safe_fs: &SafeFS,
     generated_paths: &mut Vec<String>,
 ) -> Result<()> {
     let rules_dir = resolve_path(rules_dir_str)?;
@@ -183,7 +215,9 @@ fn sync_individual_rules(
         let file_name = source_path.file_name().unwrap();
         let target_path = rules_dir.join(file_name);
 
-        fs::copy(source_path, &target_path)?;
+        let content = fs::read(source_path)
+            .with_context(|| format!("Failed to read {}", source_path.display()))?;
+        write_target(safe_fs, &target_path, &content)?;
         generated_paths.push(format!(
             "{}/{}",
             rules_dir_str.trim_end_matches('/'),
@@ -191,7 +225,7 @@ fn sync_individual_rules(
         ));
     }
 
-    println!(
+    crate::progress!(
         "   {} Generated rules in {}",
         "→".dimmed(),
         rules_dir_str.cyan()
@@ -203,7 +237,7 @@ fn sync_individual_rules(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::manifest::ClaudeAIConfig;
+    use crate::manifest::{ClaudeAIConfig, MANIFEST_FILE};
     use tempfile::tempdir;
 
     #[test]
@@ -240,6 +274,8 @@ mod tests {
     #[test]
     fn test_sync_ai_rules_idempotent() -> Result<()> {
         let dir = tempdir()?;
+        fs::write(dir.path().join(MANIFEST_FILE), "version = 1\n")?;
+        let safe_fs = SafeFS::new(dir.path(), false)?;
         let source_file = dir.path().join("rules.md");
         fs::write(&source_file, "Source rule content")?;
 
@@ -254,7 +290,7 @@ mod tests {
         });
 
         let mut generated_paths = Vec::new();
-        sync_ai_rules(&manifest, &mut generated_paths)?;
+        sync_ai_rules(&manifest, &safe_fs, &mut generated_paths)?;
 
         let content1 = fs::read_to_string(&target_file)?;
         assert!(content1.contains("Source rule content"));
@@ -262,7 +298,7 @@ mod tests {
 
         // Second run with updated source
         fs::write(&source_file, "Updated source rule content")?;
-        sync_ai_rules(&manifest, &mut generated_paths)?;
+        sync_ai_rules(&manifest, &safe_fs, &mut generated_paths)?;
 
         let content2 = fs::read_to_string(&target_file)?;
         assert!(content2.contains("Updated source rule content"));
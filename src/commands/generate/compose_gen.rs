@@ -4,16 +4,23 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::channel::mirror_image;
 use crate::manifest::Manifest;
+use crate::safe_fs::SafeFS;
 
 /// Resolve the Docker image for a service based on its framework.
 ///
 /// Each framework needs a runtime that can execute its build tools (uv, cargo, npm).
 /// Falls back to the manifest workspace image (typically node:24-alpine) for Node apps.
-fn resolve_service_image(framework: Option<&str>, workspace_image: &str) -> String {
+/// Python/Rust defaults honour `[build.images]` mirrors.
+fn resolve_service_image(
+    framework: Option<&str>,
+    workspace_image: &str,
+    images: &IndexMap<String, String>,
+) -> String {
     match framework.unwrap_or("node") {
-        "python" => "python:3.12-slim".to_string(),
-        "rust" => "rust:1-slim".to_string(),
+        "python" => mirror_image("python:3.12-slim", "python", images),
+        "rust" => mirror_image("rust:1-slim", "rust", images),
         _ => workspace_image.to_string(),
     }
 }
@@ -147,6 +154,11 @@ fn slug(s: &str) -> String {
         .to_string()
 }
 
+/// The root compose file `airis gen` writes to: an existing one, or `compose.yaml`.
+pub(super) fn compose_target_path() -> PathBuf {
+    find_existing_compose().unwrap_or_else(|| PathBuf::from("compose.yaml"))
+}
+
 /// Find an existing compose file at the project root, in Docker's official
 /// priority order: compose.yaml > compose.yml > docker-compose.yaml > docker-compose.yml.
 fn find_existing_compose() -> Option<PathBuf> {
@@ -175,12 +187,14 @@ fn find_existing_compose() -> Option<PathBuf> {
 ///   user-added services (without that marker) are preserved verbatim.
 /// - Build artifact dirs (`.next`, `.turbo`, `node_modules`, etc.) are mounted
 ///   as named volumes so they never leak to the host.
-pub fn generate_workspace_compose(manifest: &Manifest) -> Result<()> {
+pub fn generate_workspace_compose(manifest: &Manifest, safe_fs: &SafeFS) -> Result<()> {
     let mut services: IndexMap<String, ComposeService> = IndexMap::new();
     let mut volumes: IndexMap<String, ComposeVolume> = IndexMap::new();
     let networks: IndexMap<String, ComposeNetwork> = IndexMap::new();
 
     let project_name = &manifest.project.id;
+    let images = &manifest.build.images;
+    let workspace_image = mirror_image(&manifest.workspace.image, "node", images);
 
     // Workspace-wide environment variables
     let mut workspace_env = IndexMap::new();
@@ -232,7 +246,8 @@ pub fn generate_workspace_compose(manifest: &Manifest) -> Result<()> {
             framework,
             use_gpu,
             &extra_artifacts,
-            &manifest.workspace.image,
+            &workspace_image,
+            images,
             &mut volumes,
         );
         services.insert(app.name.clone(), svc);
@@ -258,7 +273,8 @@ pub fn generate_workspace_compose(manifest: &Manifest) -> Result<()> {
             framework,
             false,
             &[],
-            &manifest.workspace.image,
+            &workspace_image,
+            images,
             &mut volumes,
         );
         services.insert(name.clone(), svc);
@@ -268,7 +284,7 @@ pub fn generate_workspace_compose(manifest: &Manifest) -> Result<()> {
     services.insert(
         "workspace".to_string(),
         ComposeService {
-            image: Some(manifest.workspace.image.clone()),
+            image: Some(workspace_image.clone()),
             container_name: Some(format!("{}-workspace", project_name)),
             volumes: workspace_volumes,
             environment: workspace_env,
@@ -287,7 +303,7 @@ pub fn generate_workspace_compose(manifest: &Manifest) -> Result<()> {
     };
 
     // Merge with any existing root compose to preserve user-authored services.
-    let target_path = find_existing_compose().unwrap_or_else(|| PathBuf::from("compose.yaml"));
+    let target_path = compose_target_path();
     let final_compose = if target_path.exists() {
         merge_with_existing(generated, &target_path)?
     } else {
@@ -300,8 +316,9 @@ pub fn generate_workspace_compose(manifest: &Manifest) -> Result<()> {
         serde_yaml_ng::to_string(&final_compose).context("failed to serialize compose.yaml")?;
     let content = format!("{}{}", header, body);
 
-    fs::write(&target_path, content)
-        .with_context(|| format!("failed to write {}", target_path.display()))?;
+    // compose.yaml is merge-managed: user services were carried over above,
+    // so edit it regardless of ownership.
+    safe_fs.edit(&target_path, &content)?;
 
     Ok(())
 }
@@ -317,6 +334,7 @@ fn build_app_service(
     use_gpu_override: bool,
     stack_artifacts: &[&str],
     workspace_image: &str,
+    images: &IndexMap<String, String>,
     volumes: &mut IndexMap<String, ComposeVolume>,
 ) -> ComposeService {
     let defaults = crate::conventions::framework_defaults(framework);
@@ -377,7 +395,11 @@ fn build_app_service(
     };
 
     ComposeService {
-        image: Some(resolve_service_image(Some(framework), workspace_image)),
+        image: Some(resolve_service_image(
+            Some(framework),
+            workspace_image,
+            images,
+        )),
         container_name: Some(format!("{}-{}", project_name, name)),
         volumes: svc_volumes,
         environment: env,
@@ -599,6 +621,7 @@ mod tests {
             false,
             &[],
             "node:24-alpine",
+            &IndexMap::new(),
             &mut volumes,
         );
         assert_eq!(svc.restart.as_deref(), Some("unless-stopped"));
@@ -614,6 +637,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_app_service_uses_image_mirror() {
+        let mut images = IndexMap::new();
+        images.insert("rust".to_string(), "mirror.corp/rust".to_string());
+        let svc = build_app_service(
+            "myproj",
+            "worker",
+            "apps/worker",
+            "rust",
+            false,
+            &[],
+            "node:24-alpine",
+            &images,
+            &mut IndexMap::new(),
+        );
+        assert_eq!(svc.image.as_deref(), Some("mirror.corp/rust:1-slim"));
+    }
+
     #[test]
     fn merge_parses_gpu_resource_reservations() {
         // Regression: `deploy.resources.reservations` is a map in the Compose
@@ -0,0 +1,124 @@
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde_json::json;
+use std::fs;
+use std::path::Path;
+
+use crate::manifest::{DocsMode, Manifest};
+use crate::safe_fs::SafeFS;
+
+use super::compose_gen::compose_target_path;
+
+pub(super) const DEVCONTAINER_PATH: &str = ".devcontainer/devcontainer.json";
+
+/// Compose service the dev container attaches to (the workspace runner)
+const DEVCONTAINER_SERVICE: &str = "workspace";
+
+/// Render `.devcontainer/devcontainer.json` attached to the workspace runner
+/// service of the generated compose file.
+pub(super) fn render_devcontainer(manifest: &Manifest, compose_file: &str) -> Result<String> {
+    let workspace_folder = if manifest.workspace.workdir.is_empty() {
+        "/app"
+    } else {
+        manifest.workspace.workdir.as_str()
+    };
+
+    let value = json!({
+        "name": manifest.workspace.name,
+        "dockerComposeFile": [format!("../{}", compose_file)],
+        "service": DEVCONTAINER_SERVICE,
+        "workspaceFolder": workspace_folder,
+        "shutdownAction": "stopCompose",
+        "customizations": {
+            "vscode": {
+                "extensions": devcontainer_extensions(manifest),
+            }
+        }
+    });
+
+    let mut content =
+        serde_json::to_string_pretty(&value).context("Failed to serialize devcontainer.json")?;
+    content.push('\n');
+    Ok(content)
+}
+
+/// VS Code extensions for the frameworks declared in the manifest
+fn devcontainer_extensions(manifest: &Manifest) -> Vec<&'static str> {
+    let frameworks = manifest
+        .app
+        .iter()
+        .filter_map(|app| app.framework.as_deref())
+        .chain(
+            manifest
+                .apps
+                .values()
+                .filter_map(|app| app.framework.as_deref().or(app.app_type.as_deref())),
+        )
+        .collect::<Vec<_>>();
+
+    let mut extensions = vec!["ms-azuretools.vscode-docker", "editorconfig.editorconfig"];
+
+    let has_node =
+        frameworks.is_empty() || frameworks.iter().any(|f| !matches!(*f, "rust" | "python"));
+    if has_node {
+        extensions.extend(["dbaeumer.vscode-eslint", "esbenp.prettier-vscode"]);
+    }
+    if frameworks.contains(&"rust") {
+        extensions.push("rust-lang.rust-analyzer");
+    }
+    if frameworks.contains(&"python") {
+        extensions.extend(["ms-python.python", "charliermarsh.ruff"]);
+    }
+
+    extensions
+}
+
+/// Write `.devcontainer/devcontainer.json`.
+///
+/// An existing file that differs from the rendered one is treated like a
+/// hand-edited docs adapter: `[docs.mode = "warn"]` leaves it untouched,
+/// `"backup"` keeps a `.bak` copy before overwriting.
+pub(super) fn generate_devcontainer(manifest: &Manifest, safe_fs: &SafeFS) -> Result<()> {
+    let path = Path::new(DEVCONTAINER_PATH);
+    let compose_file = compose_target_path();
+    let content = render_devcontainer(manifest, &compose_file.to_string_lossy())?;
+
+    if safe_fs.is_unchanged(path, &content) {
+        crate::progress!("   {} {} (unchanged)", "✓".green(), DEVCONTAINER_PATH);
+        return Ok(());
+    }
+
+    if path.exists() {
+        match manifest.docs.mode {
+            DocsMode::Warn => {
+                println!(
+                    "   {} {} differs from the generated version; leaving it as is ([docs.mode = \"backup\"] to overwrite with a .bak copy)",
+                    "⚠️".yellow(),
+                    DEVCONTAINER_PATH
+                );
+                return Ok(());
+            }
+            DocsMode::Backup => {
+                let backup_path = format!("{}.bak", DEVCONTAINER_PATH);
+                fs::copy(path, &backup_path)
+                    .with_context(|| format!("Failed to create backup: {}", backup_path))?;
+                println!(
+                    "   📦 Backed up {} → {}",
+                    DEVCONTAINER_PATH.cyan(),
+                    backup_path.yellow()
+                );
+            }
+        }
+    }
+
+    // The docs mode above already decided whether to replace a hand-edited
+    // file, so edit it regardless of ownership.
+    safe_fs.edit(path, &content)?;
+    crate::progress!(
+        "   {} {} (service: {})",
+        "✓".green(),
+        DEVCONTAINER_PATH,
+        DEVCONTAINER_SERVICE
+    );
+    Ok(())
+}
@@ -0,0 +1,40 @@
+//! Target selection for `airis gen --only/--except`
+
+use anyhow::{Result, bail};
+
+/// Logical names of the generators `airis gen` runs, in run order
+pub const GEN_TARGETS: &[&str] = &["compose", "devcontainer", "tsconfig", "ai"];
+
+/// Which generators a `airis gen` run should execute
+#[derive(Debug, Clone, Default)]
+pub struct GenFilter {
+    only: Vec<String>,
+    except: Vec<String>,
+}
+
+impl GenFilter {
+    /// Build a filter from `--only` / `--except` values, rejecting unknown names
+    pub fn new(only: Vec<String>, except: Vec<String>) -> Result<Self> {
+        for name in only.iter().chain(&except) {
+            if !GEN_TARGETS.contains(&name.as_str()) {
+                bail!(
+                    "Unknown gen target '{}'. Available targets: {}",
+                    name,
+                    GEN_TARGETS.join(", ")
+                );
+            }
+        }
+        Ok(Self { only, except })
+    }
+
+    /// Whether the generator named `target` should run
+    pub fn includes(&self, target: &str) -> bool {
+        (self.only.is_empty() || self.only.iter().any(|t| t == target))
+            && !self.except.iter().any(|t| t == target)
+    }
+
+    /// Whether every generator runs (no `--only` / `--except` given)
+    pub fn is_full(&self) -> bool {
+        self.only.is_empty() && self.except.is_empty()
+    }
+}
@@ -19,8 +19,13 @@ pub(super) fn sync_lockfile(manifest: &Manifest) -> Result<()> {
         return Ok(());
     }
 
-    println!();
-    println!("{}", "🔒 Syncing pnpm-lock.yaml...".bright_blue());
+    crate::progress!();
+    crate::progress!("{}", "🔒 Syncing pnpm-lock.yaml...".bright_blue());
+
+    if let Err(e) = crate::docker::ensure_available() {
+        println!("   {} pnpm-lock.yaml sync skipped: {}", "⚠".yellow(), e);
+        return Ok(());
+    }
 
     // Find a service to use
     let docker_service = manifest
@@ -84,7 +89,7 @@ pub(super) fn sync_lockfile(manifest: &Manifest) -> Result<()> {
             }
 
             // Last resort — use lightweight docker run with base image
-            println!("   {} using docker run fallback...", "↻".yellow());
+            crate::progress!("   {} using docker run fallback...", "↻".yellow());
             let pm = &manifest.workspace.package_manager;
             let image = &manifest.workspace.image;
             Command::new("docker")
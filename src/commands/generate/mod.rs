@@ -2,42 +2,57 @@ use anyhow::Result;
 use colored::Colorize;
 use std::fs;
 use std::path::Path;
+use std::sync::OnceLock;
 
-use crate::manifest::{MANIFEST_FILE, Manifest};
-use crate::ownership::{Ownership, get_ownership};
+use crate::error::AirisError;
+use crate::manifest::{BackupStrategy, GlobalConfig, MANIFEST_FILE, Manifest};
+use crate::safe_fs::{SafeAction, SafeFS, SafeOpResult};
 use crate::templates::TemplateEngine;
 
 mod ai_gen;
 mod compose_gen;
+mod devcontainer_gen;
+mod filter;
 pub(crate) mod registry;
 mod tsconfig_gen;
 
 use compose_gen::generate_workspace_compose;
+use devcontainer_gen::generate_devcontainer;
+pub use filter::GenFilter;
 use registry::{load_generation_registry, save_generation_registry};
 use tsconfig_gen::generate_tsconfig;
+pub(crate) use tsconfig_gen::{collect_path_entries, detect_ts_major};
 
 #[cfg(test)]
 mod tests;
 
 /// CLI entry point for `airis gen`
-pub fn run(dry_run: bool, force: bool, _migrate: bool) -> Result<()> {
+///
+/// `force` removes legacy compose variants; `overwrite_user_files` lets
+/// generators replace files the user owns (after a SafeFS backup).
+pub fn run(
+    dry_run: bool,
+    force: bool,
+    overwrite_user_files: bool,
+    _migrate: bool,
+    filter: &GenFilter,
+) -> Result<()> {
     let manifest_path = Path::new(MANIFEST_FILE);
 
     if !manifest_path.exists() {
-        println!("{}", "⛔ manifest.toml not found".bright_red());
-        return Ok(());
+        return Err(AirisError::ManifestMissing.into());
     }
 
     let manifest = Manifest::load(manifest_path)?;
 
     if dry_run {
-        preview_from_manifest(&manifest)?;
+        preview_from_manifest(&manifest, filter)?;
     } else {
-        if force {
+        if force && filter.includes("compose") {
             remove_legacy_compose_files();
         }
-        println!("{}", "🧩 Regenerating workspace files...".bright_blue());
-        sync_from_manifest(&manifest)?;
+        crate::progress!("{}", "🧩 Regenerating workspace files...".bright_blue());
+        sync_selected(&manifest, filter, overwrite_user_files)?;
     }
 
     Ok(())
@@ -58,72 +73,89 @@ fn remove_legacy_compose_files() {
         let path = Path::new(name);
         if path.exists() {
             match fs::remove_file(path) {
-                Ok(()) => println!("   {} removed legacy {}", "✓".green(), name),
+                Ok(()) => crate::progress!("   {} removed legacy {}", "✓".green(), name),
                 Err(e) => println!("   {} could not remove {}: {}", "✗".red(), name, e),
             }
         }
     }
 }
 
-pub(super) fn backup_file(path: &Path) -> Result<()> {
-    if !path.exists() {
-        return Ok(());
-    }
+/// Global `backup_strategy` for `airis gen`, read once per process
+fn backup_strategy() -> &'static BackupStrategy {
+    static STRATEGY: OnceLock<BackupStrategy> = OnceLock::new();
+    STRATEGY.get_or_init(|| GlobalConfig::load().unwrap_or_default().backup_strategy)
+}
 
-    let ownership = get_ownership(path);
-    if !matches!(ownership, Ownership::Tool) {
-        return Ok(());
-    }
+/// SafeFS every generator writes through. `[ownership]` is loaded once,
+/// identical output is left alone, and overwritten files are backed up with
+/// `backup_strategy = "backup"` or when user-owned files may be overwritten.
+pub(super) fn generator_fs(overwrite_user_files: bool) -> Result<SafeFS> {
+    let backups = overwrite_user_files || *backup_strategy() == BackupStrategy::Backup;
+    Ok(SafeFS::current(false)?
+        .with_force(overwrite_user_files)
+        .with_backups(backups))
+}
 
-    let config = crate::manifest::GlobalConfig::load().unwrap_or_default();
-    match config.backup_strategy {
-        crate::manifest::BackupStrategy::None => Ok(()),
-        crate::manifest::BackupStrategy::GitCheck => {
-            let status = std::process::Command::new("git")
-                .args(["status", "--porcelain", &path.to_string_lossy()])
-                .output();
-
-            if let Ok(output) = status
-                && !output.stdout.is_empty()
-            {
-                println!(
-                    "   {} {} has uncommitted changes. Overwriting anyway.",
-                    "⚠️".yellow(),
-                    path.display()
-                );
-            }
-            Ok(())
-        }
-        crate::manifest::BackupStrategy::Backup => {
-            let backup_dir = Path::new(".airis/backups");
-            fs::create_dir_all(backup_dir)?;
-            let path_str = path.to_string_lossy().replace('/', "_");
-            let backup_path = backup_dir.join(format!("{}.latest", path_str));
-            fs::copy(path, &backup_path)?;
-            Ok(())
-        }
+/// Write a generated file, leaving existing user-owned files alone unless
+/// `safe_fs` may overwrite them
+pub(super) fn write_with_backup(
+    safe_fs: &SafeFS,
+    path: &Path,
+    content: &str,
+) -> Result<SafeOpResult> {
+    let dirty = *backup_strategy() == BackupStrategy::GitCheck && has_uncommitted_changes(path);
+    let result = safe_fs.write(path, content)?;
+    if dirty && result.action == SafeAction::Overwritten {
+        println!(
+            "   {} {} had uncommitted changes. Overwritten anyway.",
+            "⚠️".yellow(),
+            path.display()
+        );
     }
+    Ok(result)
 }
 
-pub(super) fn write_with_backup(path: &Path, content: &str) -> Result<()> {
-    let ownership = get_ownership(path);
-    if matches!(ownership, Ownership::User) {
-        return Ok(());
-    }
-    backup_file(path)?;
-    fs::write(path, content)?;
-    Ok(())
+fn has_uncommitted_changes(path: &Path) -> bool {
+    std::process::Command::new("git")
+        .args(["status", "--porcelain", &path.to_string_lossy()])
+        .output()
+        .is_ok_and(|output| !output.stdout.is_empty())
 }
 
-pub fn preview_from_manifest(_manifest: &Manifest) -> Result<()> {
+pub fn preview_from_manifest(_manifest: &Manifest, filter: &GenFilter) -> Result<()> {
     println!("{}", "📋 Files that would be generated:".bright_yellow());
-    println!("   - compose.yaml");
-    println!("   - tsconfig.json");
+    let files = [
+        ("compose", "compose.yaml"),
+        ("devcontainer", ".devcontainer/devcontainer.json"),
+        ("tsconfig", "tsconfig.json"),
+    ];
+    for (target, file) in files {
+        if filter.includes(target) {
+            println!("   - {}", file);
+        }
+    }
     Ok(())
 }
 
+/// Run every generator
 pub fn sync_from_manifest(manifest: &Manifest) -> Result<()> {
+    sync_selected(manifest, &GenFilter::default(), false)
+}
+
+/// Run the generators selected by `filter`. With `overwrite_user_files`,
+/// generated files that are user-owned are overwritten too (after a SafeFS
+/// backup).
+///
+/// Orphan cleanup only happens on a full run: a partial run can't tell a
+/// file that is no longer generated from one whose generator was skipped, so
+/// it keeps the previous registry entries.
+pub fn sync_selected(
+    manifest: &Manifest,
+    filter: &GenFilter,
+    overwrite_user_files: bool,
+) -> Result<()> {
     let engine = TemplateEngine::new()?;
+    let safe_fs = generator_fs(overwrite_user_files)?;
     let mut generated_paths: Vec<String> = Vec::new();
 
     let registry_path = Path::new(".airis/generated.toml");
@@ -134,22 +166,41 @@ pub fn sync_from_manifest(manifest: &Manifest) -> Result<()> {
 
         // Always generate Docker Compose to ensure environment isolation (Hygiene).
         // Convention-based discovery ensures projects are managed even if not in manifest.toml.
-        generate_workspace_compose(manifest)?;
-        generated_paths.push("compose.yaml".into());
+        if filter.includes("compose") {
+            generate_workspace_compose(manifest, &safe_fs)?;
+            generated_paths.push("compose.yaml".into());
+        }
+
+        // Dev container attached to the workspace runner. Not recorded in the
+        // generation registry: a hand-edited devcontainer must never be
+        // removed as an orphan.
+        if filter.includes("devcontainer") {
+            generate_devcontainer(manifest, &safe_fs)?;
+        }
 
         // Generate TSConfig paths (Derived from discovery)
-        if !manifest.typescript.skip {
-            generate_tsconfig(manifest, &engine, &resolved_catalog)?;
+        if filter.includes("tsconfig") && !manifest.typescript.skip {
+            generate_tsconfig(manifest, &engine, &resolved_catalog, &safe_fs)?;
             generated_paths.extend(["tsconfig.base.json".into(), "tsconfig.json".into()]);
         }
     }
 
     // Generate AI instructions (Issue #203)
-    ai_gen::sync_ai_rules(manifest, &mut generated_paths)?;
+    if filter.includes("ai") {
+        ai_gen::sync_ai_rules(manifest, &safe_fs, &mut generated_paths)?;
+    }
 
-    // Clean up orphaned files that are no longer being generated (e.g. package.json, hooks)
-    crate::commands::clean::remove_orphaned_files(&previous_paths, &generated_paths, false);
-    save_generation_registry(registry_path, &generated_paths)?;
+    if filter.is_full() {
+        // Clean up orphaned files that are no longer being generated (e.g. package.json, hooks)
+        crate::commands::clean::remove_orphaned_files(&previous_paths, &generated_paths, false);
+    } else {
+        for path in previous_paths {
+            if !generated_paths.contains(&path) {
+                generated_paths.push(path);
+            }
+        }
+    }
+    save_generation_registry(&safe_fs, registry_path, &generated_paths)?;
 
     println!("\n{} Generation complete.", "✅".green());
     Ok(())
@@ -4,6 +4,7 @@ use indexmap::IndexMap;
 use std::path::Path;
 
 use crate::manifest::Manifest;
+use crate::safe_fs::SafeFS;
 use crate::templates::TemplateEngine;
 
 use super::write_with_backup;
@@ -12,11 +13,11 @@ pub(super) fn generate_package_json(
     manifest: &Manifest,
     engine: &TemplateEngine,
     resolved_catalog: &IndexMap<String, String>,
-    _force: bool,
+    safe_fs: &SafeFS,
 ) -> Result<()> {
     let path = Path::new("package.json");
     let content = engine.render_package_json(manifest, resolved_catalog)?;
-    write_with_backup(path, &content)?;
+    write_with_backup(safe_fs, path, &content)?;
     println!(
         "   {} package.json (synced from manifest.toml)",
         "✓".green()
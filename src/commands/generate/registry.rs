@@ -2,6 +2,8 @@ use anyhow::{Context, Result};
 use std::fs;
 use std::path::Path;
 
+use crate::safe_fs::SafeFS;
+
 /// Load the list of previously generated files from .airis/generated.toml
 pub(crate) fn load_generation_registry(path: &Path) -> Vec<String> {
     let content = match fs::read_to_string(path) {
@@ -17,10 +19,11 @@ pub(crate) fn load_generation_registry(path: &Path) -> Vec<String> {
 }
 
 /// Save the current list of generated files to .airis/generated.toml
-pub(super) fn save_generation_registry(path: &Path, paths: &[String]) -> Result<()> {
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)?;
-    }
+pub(super) fn save_generation_registry(
+    safe_fs: &SafeFS,
+    path: &Path,
+    paths: &[String],
+) -> Result<()> {
     let mut sorted = paths.to_vec();
     sorted.sort();
     sorted.dedup();
@@ -28,6 +31,8 @@ pub(super) fn save_generation_registry(path: &Path, paths: &[String]) -> Result<
         "# Auto-managed by airis gen — do not edit\n# Lists all files generated from manifest.toml\n{}\n",
         sorted.join("\n")
     );
-    fs::write(path, content).context("Failed to write generation registry")?;
+    safe_fs
+        .write(path, content)
+        .context("Failed to write generation registry")?;
     Ok(())
 }
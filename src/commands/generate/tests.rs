@@ -1,10 +1,45 @@
 use indexmap::IndexMap;
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 use crate::manifest::Manifest;
+use crate::safe_fs::SafeFS;
 
+use super::devcontainer_gen::render_devcontainer;
 use super::registry::{load_generation_registry, save_generation_registry};
 use super::tsconfig_gen::detect_ts_major;
+use super::{GenFilter, sync_from_manifest};
+
+// ── GenFilter ──
+
+#[test]
+fn test_gen_filter_default_runs_everything() {
+    let filter = GenFilter::default();
+    assert!(filter.is_full());
+    assert!(filter.includes("compose"));
+    assert!(filter.includes("ai"));
+}
+
+#[test]
+fn test_gen_filter_only_and_except() {
+    let only = GenFilter::new(vec!["tsconfig".into(), "ai".into()], vec![]).unwrap();
+    assert!(!only.is_full());
+    assert!(only.includes("tsconfig"));
+    assert!(!only.includes("compose"));
+
+    let except = GenFilter::new(vec![], vec!["compose".into()]).unwrap();
+    assert!(!except.includes("compose"));
+    assert!(except.includes("devcontainer"));
+}
+
+#[test]
+fn test_gen_filter_rejects_unknown_target() {
+    let err = GenFilter::new(vec!["ci".into()], vec![]).unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("'ci'"));
+    assert!(msg.contains("compose, devcontainer, tsconfig, ai"));
+}
 
 // ── detect_ts_major ──
 
@@ -72,6 +107,8 @@ fn test_load_generation_registry_missing_file() {
 #[test]
 fn test_save_generation_registry_deduplicates_and_sorts() {
     let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("manifest.toml"), "version = 1\n").unwrap();
+    let safe_fs = SafeFS::new(dir.path(), false).unwrap();
     let path = dir.path().join(".airis").join("generated.toml");
 
     let paths = vec![
@@ -79,7 +116,7 @@ fn test_save_generation_registry_deduplicates_and_sorts() {
         "package.json".to_string(),
         "tsconfig.json".to_string(), // duplicate
     ];
-    save_generation_registry(&path, &paths).unwrap();
+    save_generation_registry(&safe_fs, &path, &paths).unwrap();
 
     let content = fs::read_to_string(&path).unwrap();
     assert!(content.contains("tsconfig.json"));
@@ -90,7 +127,121 @@ fn test_save_generation_registry_deduplicates_and_sorts() {
     assert!(pkg_pos < ts_pos);
 }
 
+// ── sync_from_manifest ──
+
+/// Every file under `root` with its content and modification time
+fn snapshot(root: &Path) -> Vec<(PathBuf, Vec<u8>, SystemTime)> {
+    let mut files: Vec<_> = walkdir::WalkDir::new(root)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| {
+            let meta = e.metadata().unwrap();
+            let content = fs::read(e.path()).unwrap();
+            (e.path().to_path_buf(), content, meta.modified().unwrap())
+        })
+        .collect();
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    files
+}
+
+#[test]
+fn test_sync_from_manifest_twice_leaves_files_untouched() {
+    let _guard = crate::test_lock::DIR_LOCK.lock().unwrap();
+    let manifest_content = r#"
+version = 1
+[project]
+id = "test"
+
+[workspace]
+name = "test"
+package_manager = "pnpm@10.22.0"
+
+[ai]
+shared_rules = ["docs/rules.md"]
+
+[ai.claude]
+target = "CLAUDE.md"
+rules_dir = ".claude/rules"
+"#;
+    let manifest: Manifest = toml::from_str(manifest_content).unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("manifest.toml"), manifest_content).unwrap();
+    fs::create_dir_all(dir.path().join("docs")).unwrap();
+    fs::write(dir.path().join("docs/rules.md"), "Use pnpm.\n").unwrap();
+    fs::write(dir.path().join("CLAUDE.md"), "# Notes\n").unwrap();
+
+    let original_dir = std::env::current_dir().unwrap();
+    std::env::set_current_dir(dir.path()).unwrap();
+
+    let result = (|| -> anyhow::Result<_> {
+        sync_from_manifest(&manifest)?;
+        let first = snapshot(dir.path());
+        sync_from_manifest(&manifest)?;
+        Ok((first, snapshot(dir.path())))
+    })();
+
+    std::env::set_current_dir(original_dir).unwrap();
+
+    let (first, second) = result.unwrap();
+    assert!(first.iter().any(|(p, _, _)| p.ends_with("compose.yaml")));
+    assert!(first.iter().any(|(p, _, _)| p.ends_with("tsconfig.json")));
+    // Same files (no new backups), same content, same mtimes
+    assert_eq!(first, second);
+}
+
 /// Helper: create a minimal manifest for testing
 fn default_test_manifest() -> Manifest {
     toml::from_str("version = 1\n[project]\nid = \"test\"").unwrap()
 }
+
+// ── render_devcontainer ──
+
+#[test]
+fn test_render_devcontainer_targets_workspace_service() {
+    let manifest: Manifest = toml::from_str(
+        r#"
+version = 1
+[project]
+id = "test"
+
+[workspace]
+name = "demo"
+workdir = "/srv/app"
+
+[[app]]
+name = "api"
+framework = "rust"
+"#,
+    )
+    .unwrap();
+
+    let content = render_devcontainer(&manifest, "compose.yaml").unwrap();
+    let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+    assert_eq!(json["name"], "demo");
+    assert_eq!(json["dockerComposeFile"][0], "../compose.yaml");
+    assert_eq!(json["service"], "workspace");
+    assert_eq!(json["workspaceFolder"], "/srv/app");
+
+    let extensions = json["customizations"]["vscode"]["extensions"]
+        .as_array()
+        .unwrap();
+    assert!(extensions.contains(&"rust-lang.rust-analyzer".into()));
+    // Rust-only workspace: no JS tooling
+    assert!(!extensions.contains(&"dbaeumer.vscode-eslint".into()));
+}
+
+#[test]
+fn test_render_devcontainer_defaults_to_node_tooling() {
+    let manifest = default_test_manifest();
+    let content = render_devcontainer(&manifest, "docker-compose.yml").unwrap();
+    let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+    assert_eq!(json["dockerComposeFile"][0], "../docker-compose.yml");
+    let extensions = json["customizations"]["vscode"]["extensions"]
+        .as_array()
+        .unwrap();
+    assert!(extensions.contains(&"dbaeumer.vscode-eslint".into()));
+}
@@ -7,6 +7,7 @@ use std::path::{Path, PathBuf};
 
 use crate::commands::discover::discover_from_workspaces;
 use crate::manifest::Manifest;
+use crate::safe_fs::SafeFS;
 use crate::templates::TemplateEngine;
 
 use super::write_with_backup;
@@ -15,64 +16,40 @@ pub(super) fn generate_tsconfig(
     manifest: &Manifest,
     engine: &TemplateEngine,
     resolved_catalog: &IndexMap<String, String>,
+    safe_fs: &SafeFS,
 ) -> Result<()> {
-    println!();
-    println!("{}", "📝 Generating tsconfig files...".bright_blue());
+    crate::progress!();
+    crate::progress!("{}", "📝 Generating tsconfig files...".bright_blue());
 
     let ts_major = detect_ts_major(manifest, resolved_catalog);
 
     // 1. tsconfig.base.json — shared compilerOptions
     let base_content = engine.render_tsconfig_base(manifest)?;
     let base_path = Path::new("tsconfig.base.json");
-    write_with_backup(base_path, &base_content)?;
-    println!(
+    write_with_backup(safe_fs, base_path, &base_content)?;
+    crate::progress!(
         "   {} tsconfig.base.json (shared compilerOptions)",
         "✓".green()
     );
 
     // 2. Collect workspace paths for IDE path aliases
     let workspace_root = env::current_dir().context("Failed to get current directory")?;
-    let workspace_patterns = if !manifest.packages.workspaces.is_empty() {
-        &manifest.packages.workspaces
-    } else {
-        &manifest.workspace.workspaces
-    };
-
-    let mut path_entries: Vec<(String, String)> = Vec::new();
-    if !workspace_patterns.is_empty() {
-        let discovered = discover_from_workspaces(workspace_patterns, &workspace_root)?;
-        for disc in &discovered {
-            // Skip node_modules and build artifacts
-            if disc.path.contains("node_modules")
-                || disc.path.contains(".next")
-                || disc.path.contains("/dist/")
-            {
-                continue;
-            }
-            let pkg_json_path = workspace_root.join(&disc.path).join("package.json");
-            if let Ok(content) = fs::read_to_string(&pkg_json_path)
-                && let Ok(json) = serde_json::from_str::<serde_json::Value>(&content)
-                && let Some(name) = json.get("name").and_then(|n| n.as_str())
-            {
-                path_entries.push((name.to_string(), disc.path.clone()));
-            }
-        }
-    }
+    let path_entries = collect_path_entries(manifest, &workspace_root)?;
 
     // 3. tsconfig.json — IDE config with paths
     let root_content = engine.render_tsconfig_root(manifest, &path_entries, ts_major)?;
     let root_path = Path::new("tsconfig.json");
-    write_with_backup(root_path, &root_content)?;
+    write_with_backup(safe_fs, root_path, &root_content)?;
 
     if ts_major >= 6 {
-        println!(
+        crate::progress!(
             "   {} tsconfig.json (IDE, {} paths, TS{} — ignoreDeprecations: \"6.0\")",
             "✓".green(),
             path_entries.len(),
             ts_major,
         );
     } else {
-        println!(
+        crate::progress!(
             "   {} tsconfig.json (IDE, {} paths, TS{})",
             "✓".green(),
             path_entries.len(),
@@ -120,7 +97,7 @@ pub(super) fn generate_tsconfig(
             let pkg_tsconfig =
                 engine.render_package_tsconfig(app, manifest, &rel_to_root, ts_major)?;
             let tsconfig_path = pkg_path.join("tsconfig.json");
-            write_with_backup(&tsconfig_path, &pkg_tsconfig)?;
+            write_with_backup(safe_fs, &tsconfig_path, &pkg_tsconfig)?;
             pkg_count += 1;
 
             // Generate css.d.ts for Next.js apps (TS6 TS2882 fix)
@@ -129,7 +106,7 @@ pub(super) fn generate_tsconfig(
                 let src_dir = pkg_path.join("src");
                 if src_dir.exists() {
                     let css_path = src_dir.join("css.d.ts");
-                    write_with_backup(&css_path, &css_decl)?;
+                    write_with_backup(safe_fs, &css_path, &css_decl)?;
                     css_count += 1;
                 }
             }
@@ -150,8 +127,43 @@ pub(super) fn generate_tsconfig(
     Ok(())
 }
 
+/// Collect (package name, path) pairs for tsconfig.json path aliases.
+pub(crate) fn collect_path_entries(
+    manifest: &Manifest,
+    workspace_root: &Path,
+) -> Result<Vec<(String, String)>> {
+    let workspace_patterns = if !manifest.packages.workspaces.is_empty() {
+        &manifest.packages.workspaces
+    } else {
+        &manifest.workspace.workspaces
+    };
+
+    let mut path_entries: Vec<(String, String)> = Vec::new();
+    if !workspace_patterns.is_empty() {
+        let discovered = discover_from_workspaces(workspace_patterns, workspace_root)?;
+        for disc in &discovered {
+            // Skip node_modules and build artifacts
+            if disc.path.contains("node_modules")
+                || disc.path.contains(".next")
+                || disc.path.contains("/dist/")
+            {
+                continue;
+            }
+            let pkg_json_path = workspace_root.join(&disc.path).join("package.json");
+            if let Ok(content) = fs::read_to_string(&pkg_json_path)
+                && let Ok(json) = serde_json::from_str::<serde_json::Value>(&content)
+                && let Some(name) = json.get("name").and_then(|n| n.as_str())
+            {
+                path_entries.push((name.to_string(), disc.path.clone()));
+            }
+        }
+    }
+
+    Ok(path_entries)
+}
+
 /// Detect TypeScript major version from manifest or resolved catalog.
-pub(super) fn detect_ts_major(
+pub(crate) fn detect_ts_major(
     manifest: &Manifest,
     resolved_catalog: &IndexMap<String, String>,
 ) -> u32 {
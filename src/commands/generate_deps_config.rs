@@ -0,0 +1,167 @@
+//! Renovate / Dependabot config aligned with the pnpm catalog
+//!
+//! Dependency bots should bump catalog versions in `pnpm-workspace.yaml`
+//! (grouped into one PR) and leave files regenerated by `airis gen` alone,
+//! otherwise their edits are overwritten on the next `airis gen`.
+
+use anyhow::{Context, Result, bail};
+use colored::Colorize;
+use serde_json::json;
+use std::path::Path;
+
+use crate::commands::generate::registry::load_generation_registry;
+use crate::safe_fs::{SafeAction, SafeFS};
+
+const REGISTRY_PATH: &str = ".airis/generated.toml";
+const RENOVATE_PATH: &str = "renovate.json";
+const DEPENDABOT_PATH: &str = ".github/dependabot.yml";
+
+/// Write `renovate.json` or `.github/dependabot.yml` for the current workspace
+pub fn run(target: &str) -> Result<()> {
+    let catalog: Vec<String> = crate::pnpm::read_workspace_catalog().into_keys().collect();
+    let generated = load_generation_registry(Path::new(REGISTRY_PATH));
+
+    let (path, content) = match target {
+        "renovate" => (RENOVATE_PATH, render_renovate(&catalog, &generated)?),
+        "dependabot" => (DEPENDABOT_PATH, render_dependabot(&catalog, &generated)?),
+        other => bail!(
+            "Unknown target '{}'. Use 'renovate' or 'dependabot'.",
+            other
+        ),
+    };
+
+    let result = SafeFS::current(false)?.edit(path, &content)?;
+    if result.action == SafeAction::Unchanged {
+        println!("{} {} is up to date", "✅".green(), path);
+        return Ok(());
+    }
+    println!("{} Wrote {}", "✅".green(), path.bold());
+    if let Some(backup) = result.backup {
+        println!("   {} Backup: {}", "📦".dimmed(), backup.display());
+    }
+    println!(
+        "   {} catalog package(s) grouped, {} generated file(s) ignored",
+        catalog.len(),
+        generated.len()
+    );
+
+    Ok(())
+}
+
+/// Render `renovate.json`: generated files ignored, catalog bumped as one group
+pub(crate) fn render_renovate(catalog: &[String], generated: &[String]) -> Result<String> {
+    // Setting ignorePaths replaces Renovate's defaults, so keep node_modules
+    let mut ignore_paths = vec!["**/node_modules/**".to_string()];
+    ignore_paths.extend(generated.iter().cloned());
+
+    let mut package_rules = Vec::new();
+    if !catalog.is_empty() {
+        package_rules.push(json!({
+            "description": "Versions come from the pnpm catalog (pnpm-workspace.yaml); bump them together",
+            "matchPackageNames": catalog,
+            "groupName": "pnpm catalog",
+        }));
+    }
+
+    let value = json!({
+        "$schema": "https://docs.renovatebot.com/renovate-schema.json",
+        "extends": ["config:recommended"],
+        "ignorePaths": ignore_paths,
+        "packageRules": package_rules,
+    });
+
+    let mut content =
+        serde_json::to_string_pretty(&value).context("Failed to serialize renovate.json")?;
+    content.push('\n');
+    Ok(content)
+}
+
+/// Render `.github/dependabot.yml`: generated files excluded, catalog grouped
+pub(crate) fn render_dependabot(catalog: &[String], generated: &[String]) -> Result<String> {
+    let mut npm = json!({
+        "package-ecosystem": "npm",
+        "directory": "/",
+        "schedule": { "interval": "weekly" },
+    });
+    if !generated.is_empty() {
+        npm["exclude-paths"] = json!(generated);
+    }
+    if !catalog.is_empty() {
+        npm["groups"] = json!({ "catalog": { "patterns": catalog } });
+    }
+
+    let value = json!({
+        "version": 2,
+        "updates": [
+            npm,
+            {
+                "package-ecosystem": "github-actions",
+                "directory": "/",
+                "schedule": { "interval": "weekly" },
+            },
+        ],
+    });
+
+    let yaml = serde_yaml_ng::to_string(&value).context("Failed to serialize dependabot.yml")?;
+    Ok(format!(
+        "# Generated by `airis generate deps-config --target dependabot`\n{}",
+        yaml
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_render_renovate_groups_catalog_and_ignores_generated() {
+        let content =
+            render_renovate(&names(&["react", "next"]), &names(&["compose.yaml"])).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+        assert_eq!(
+            json["ignorePaths"],
+            json!(["**/node_modules/**", "compose.yaml"])
+        );
+        assert_eq!(
+            json["packageRules"][0]["matchPackageNames"],
+            json!(["react", "next"])
+        );
+        assert_eq!(json["packageRules"][0]["groupName"], "pnpm catalog");
+
+        // Idempotent: same inputs, same bytes
+        assert_eq!(
+            content,
+            render_renovate(&names(&["react", "next"]), &names(&["compose.yaml"])).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_render_renovate_without_catalog() {
+        let content = render_renovate(&[], &[]).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(json["packageRules"], json!([]));
+    }
+
+    #[test]
+    fn test_render_dependabot() {
+        let content = render_dependabot(&names(&["react"]), &names(&["tsconfig.json"])).unwrap();
+        let yaml: serde_yaml_ng::Value = serde_yaml_ng::from_str(&content).unwrap();
+
+        let npm = &yaml["updates"][0];
+        assert_eq!(npm["package-ecosystem"].as_str(), Some("npm"));
+        assert_eq!(npm["exclude-paths"][0].as_str(), Some("tsconfig.json"));
+        assert_eq!(
+            npm["groups"]["catalog"]["patterns"][0].as_str(),
+            Some("react")
+        );
+        assert_eq!(
+            yaml["updates"][1]["package-ecosystem"].as_str(),
+            Some("github-actions")
+        );
+    }
+}
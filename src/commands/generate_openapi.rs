@@ -0,0 +1,654 @@
+//! TypeScript types and a fetch client from an OpenAPI 3 document
+
+use anyhow::{Context, Result, bail};
+use colored::Colorize;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+const SCHEMA_PREFIX: &str = "#/components/schemas/";
+
+/// Generate `api.types.ts` and `client.ts` from an OpenAPI 3 spec (path or URL)
+pub fn run(input: &str, output: &str) -> Result<()> {
+    println!(
+        "{}",
+        "🔧 Generating TypeScript types from OpenAPI..."
+            .cyan()
+            .bold()
+    );
+    println!("   {} Input: {}", "📄".dimmed(), input);
+    println!("   {} Output: {}", "📂".dimmed(), output);
+    println!();
+
+    let doc = load_document(input)?;
+    let version = doc.get("openapi").and_then(|v| v.as_str()).unwrap_or("");
+    if !version.starts_with('3') {
+        bail!(
+            "Only OpenAPI 3.x documents are supported (found '{}')",
+            version
+        );
+    }
+
+    // Check if output directory exists, create if not
+    let output_path = Path::new(output);
+    if !output_path.exists() {
+        println!("   {} Creating output directory: {}", "📁".dimmed(), output);
+        fs::create_dir_all(output_path)
+            .with_context(|| format!("Failed to create directory: {}", output))?;
+    }
+
+    for (file, content) in [
+        ("api.types.ts", render_types(&doc)),
+        ("client.ts", render_client(&doc)),
+    ] {
+        let target = output_path.join(file);
+        fs::write(&target, content)
+            .with_context(|| format!("Failed to write {}", target.display()))?;
+        println!("   {} Wrote {}", "✅".green(), target.display());
+    }
+
+    println!();
+    println!(
+        "{}",
+        "✅ TypeScript types generated successfully!".green().bold()
+    );
+
+    Ok(())
+}
+
+/// Read a JSON or YAML document from a file or http(s) URL
+fn load_document(input: &str) -> Result<Value> {
+    let content = if input.starts_with("http://") || input.starts_with("https://") {
+        ureq::get(input)
+            .call()
+            .with_context(|| format!("Failed to fetch {}", input))?
+            .into_body()
+            .read_to_string()
+            .with_context(|| format!("Failed to read response body from {}", input))?
+    } else {
+        fs::read_to_string(input).with_context(|| format!("Failed to read {}", input))?
+    };
+
+    serde_json::from_str(&content)
+        .or_else(|_| serde_yaml_ng::from_str(&content))
+        .with_context(|| format!("Failed to parse {} as JSON or YAML", input))
+}
+
+/// Follow a local JSON pointer (`#/components/...`)
+fn resolve_ref<'a>(doc: &'a Value, reference: &str) -> Option<&'a Value> {
+    let pointer = reference.strip_prefix('#')?;
+    let pointer = pointer.replace("~1", "/").replace("~0", "~");
+    doc.pointer(&pointer)
+}
+
+/// Turn a schema name into a valid TypeScript identifier
+fn type_name(name: &str) -> String {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let mut chars = s.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Quote property names that aren't valid identifiers
+fn property_key(name: &str) -> String {
+    let valid = name.chars().enumerate().all(|(i, c)| {
+        c == '_' || c == '$' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit())
+    });
+    if valid && !name.is_empty() {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name)
+    }
+}
+
+/// Render a schema as a TypeScript type expression. Schema references are
+/// emitted as `<ns><Name>`, so the client can point them at its `T.` import.
+fn ts_type(doc: &Value, schema: &Value, ns: &str, depth: usize) -> String {
+    // Guard against pathological inline recursion
+    if depth > 32 {
+        return "unknown".to_string();
+    }
+
+    if let Some(reference) = schema.get("$ref").and_then(|v| v.as_str()) {
+        if let Some(name) = reference.strip_prefix(SCHEMA_PREFIX) {
+            return format!("{}{}", ns, type_name(name));
+        }
+        // Other local refs (parameters, responses, ...) are inlined
+        return resolve_ref(doc, reference)
+            .map(|target| ts_type(doc, target, ns, depth + 1))
+            .unwrap_or_else(|| "unknown".to_string());
+    }
+
+    let base = if let Some(parts) = schema.get("allOf").and_then(|v| v.as_array()) {
+        join_types(doc, parts, " & ", ns, depth)
+    } else if let Some(parts) = schema
+        .get("oneOf")
+        .or_else(|| schema.get("anyOf"))
+        .and_then(|v| v.as_array())
+    {
+        join_types(doc, parts, " | ", ns, depth)
+    } else if let Some(values) = schema.get("enum").and_then(|v| v.as_array()) {
+        values
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(" | ")
+    } else {
+        match schema_type(schema) {
+            Some("string") => "string".to_string(),
+            Some("integer") | Some("number") => "number".to_string(),
+            Some("boolean") => "boolean".to_string(),
+            Some("null") => "null".to_string(),
+            Some("array") => {
+                let items = schema
+                    .get("items")
+                    .map(|items| ts_type(doc, items, ns, depth + 1))
+                    .unwrap_or_else(|| "unknown".to_string());
+                if items.contains(' ') {
+                    format!("Array<{}>", items)
+                } else {
+                    format!("{}[]", items)
+                }
+            }
+            Some("object") | None if schema.get("properties").is_some() => {
+                object_type(doc, schema, ns, depth)
+            }
+            Some("object") => match schema.get("additionalProperties") {
+                Some(Value::Object(_)) => format!(
+                    "Record<string, {}>",
+                    ts_type(doc, &schema["additionalProperties"], ns, depth + 1)
+                ),
+                _ => "Record<string, unknown>".to_string(),
+            },
+            _ => "unknown".to_string(),
+        }
+    };
+
+    if is_nullable(schema) && base != "null" {
+        format!("{} | null", base)
+    } else {
+        base
+    }
+}
+
+/// `type` may be a string or (3.1) an array like `["string", "null"]`
+fn schema_type(schema: &Value) -> Option<&str> {
+    match schema.get("type") {
+        Some(Value::String(t)) => Some(t.as_str()),
+        Some(Value::Array(types)) => types
+            .iter()
+            .filter_map(|t| t.as_str())
+            .find(|t| *t != "null"),
+        _ => None,
+    }
+}
+
+fn is_nullable(schema: &Value) -> bool {
+    schema.get("nullable").and_then(|v| v.as_bool()) == Some(true)
+        || schema
+            .get("type")
+            .and_then(|v| v.as_array())
+            .is_some_and(|types| types.iter().any(|t| t == "null"))
+}
+
+fn join_types(doc: &Value, parts: &[Value], sep: &str, ns: &str, depth: usize) -> String {
+    let types: Vec<String> = parts
+        .iter()
+        .map(|p| {
+            let t = ts_type(doc, p, ns, depth + 1);
+            if t.contains(' ') && !t.starts_with('{') {
+                format!("({})", t)
+            } else {
+                t
+            }
+        })
+        .collect();
+    if types.is_empty() {
+        "unknown".to_string()
+    } else {
+        types.join(sep)
+    }
+}
+
+/// Inline object literal type
+fn object_type(doc: &Value, schema: &Value, ns: &str, depth: usize) -> String {
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(|v| v.as_array())
+        .map(|r| r.iter().filter_map(|v| v.as_str()).collect())
+        .unwrap_or_default();
+
+    let indent = "  ".repeat(depth + 1);
+    let mut out = String::from("{\n");
+    if let Some(props) = schema.get("properties").and_then(|v| v.as_object()) {
+        for (name, prop) in props {
+            let optional = if required.contains(&name.as_str()) {
+                ""
+            } else {
+                "?"
+            };
+            out.push_str(&format!(
+                "{}{}{}: {};\n",
+                indent,
+                property_key(name),
+                optional,
+                ts_type(doc, prop, ns, depth + 1)
+            ));
+        }
+    }
+    out.push_str(&"  ".repeat(depth));
+    out.push('}');
+    out
+}
+
+/// Render `components.schemas` as exported types
+fn render_types(doc: &Value) -> String {
+    let mut out = String::from("// Generated by `airis generate openapi`. Do not edit manually.\n");
+
+    let Some(schemas) = doc
+        .pointer("/components/schemas")
+        .and_then(|v| v.as_object())
+    else {
+        return out;
+    };
+
+    for (name, schema) in schemas {
+        let body = ts_type(doc, schema, "", 0);
+        if body.starts_with('{') && !is_nullable(schema) {
+            out.push_str(&format!(
+                "\nexport interface {} {}\n",
+                type_name(name),
+                body
+            ));
+        } else {
+            out.push_str(&format!("\nexport type {} = {};\n", type_name(name), body));
+        }
+    }
+
+    out
+}
+
+/// camelCase function name for an operation
+fn operation_name(method: &str, path: &str, operation: &Value) -> String {
+    let raw = operation
+        .get("operationId")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| format!("{} {}", method, path));
+    camel_case(&raw)
+}
+
+/// camelCase identifier (`user-id` → `userId`); a leading digit gets a `_`
+fn camel_case(raw: &str) -> String {
+    let pascal = type_name(raw);
+    let mut chars = pascal.chars();
+    let camel: String = chars
+        .next()
+        .map(|c| c.to_lowercase().chain(chars).collect())
+        .unwrap_or_default();
+    if camel.is_empty() || camel.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", camel)
+    } else {
+        camel
+    }
+}
+
+/// Query parameters of an operation (path-item level ones included), as
+/// `(name, required, schema)`
+fn query_params<'a>(
+    doc: &'a Value,
+    item: &'a Value,
+    operation: &'a Value,
+) -> Vec<(&'a str, bool, &'a Value)> {
+    [item, operation]
+        .into_iter()
+        .filter_map(|v| v.get("parameters").and_then(|p| p.as_array()))
+        .flatten()
+        .filter_map(|param| match param.get("$ref").and_then(|v| v.as_str()) {
+            Some(reference) => resolve_ref(doc, reference),
+            None => Some(param),
+        })
+        .filter(|param| param.get("in").and_then(|v| v.as_str()) == Some("query"))
+        .filter_map(|param| {
+            let name = param.get("name")?.as_str()?;
+            let required = param.get("required").and_then(|v| v.as_bool()) == Some(true);
+            Some((name, required, param.get("schema").unwrap_or(&Value::Null)))
+        })
+        .collect()
+}
+
+/// TypeScript type of the first 2xx JSON response
+fn response_type(doc: &Value, operation: &Value, ns: &str) -> String {
+    let Some(responses) = operation.get("responses").and_then(|v| v.as_object()) else {
+        return "void".to_string();
+    };
+
+    responses
+        .iter()
+        .filter(|(code, _)| code.starts_with('2'))
+        .find_map(|(_, response)| {
+            let response = match response.get("$ref").and_then(|v| v.as_str()) {
+                Some(reference) => resolve_ref(doc, reference)?,
+                None => response,
+            };
+            response
+                .pointer("/content/application~1json/schema")
+                .map(|schema| ts_type(doc, schema, ns, 0))
+        })
+        .unwrap_or_else(|| "void".to_string())
+}
+
+/// Preamble of the generated fetch client
+const CLIENT_PREAMBLE: &str = "// Generated by `airis generate openapi`. Do not edit manually.\n\
+     import type * as T from \"./api.types\";\n\n\
+     let baseUrl = \"\";\n\n\
+     /** Point every request at `url` (e.g. \"https://api.example.com\"). */\n\
+     export function setBaseUrl(url: string): void {\n\
+     \x20 baseUrl = url;\n\
+     }\n\n\
+     async function request<R>(method: string, path: string, body?: unknown, init?: RequestInit): Promise<R> {\n\
+     \x20 const res = await fetch(baseUrl + path, {\n\
+     \x20   ...init,\n\
+     \x20   method,\n\
+     \x20   headers: { \"Content-Type\": \"application/json\", ...init?.headers },\n\
+     \x20   body: body === undefined ? undefined : JSON.stringify(body),\n\
+     \x20 });\n\
+     \x20 if (!res.ok) throw new Error(`${method} ${path} failed: ${res.status}`);\n\
+     \x20 return (res.status === 204 ? undefined : await res.json()) as R;\n\
+     }\n";
+
+/// Query-string helper, emitted only when some operation takes query params
+/// (an unused module-level function would trip `noUnusedLocals`)
+const WITH_QUERY_HELPER: &str = "\nfunction withQuery(path: string, query?: Record<string, unknown>): string {\n\
+     \x20 const params = new URLSearchParams();\n\
+     \x20 for (const [key, value] of Object.entries(query ?? {})) {\n\
+     \x20   for (const item of Array.isArray(value) ? value : [value]) {\n\
+     \x20     if (item !== undefined && item !== null) params.append(key, String(item));\n\
+     \x20   }\n\
+     \x20 }\n\
+     \x20 const search = params.toString();\n\
+     \x20 return search ? `${path}?${search}` : path;\n\
+     }\n";
+
+/// Render a minimal typed fetch client: one function per operation
+fn render_client(doc: &Value) -> String {
+    let mut out = String::from(CLIENT_PREAMBLE);
+    let mut operations = String::new();
+
+    let paths = doc.get("paths").and_then(|v| v.as_object());
+    for (path, item) in paths.into_iter().flatten() {
+        for method in ["get", "post", "put", "patch", "delete"] {
+            let Some(operation) = item.get(method) else {
+                continue;
+            };
+
+            // Spec names (`{user-id}`) paired with TS identifiers (`userId`)
+            let path_params: Vec<(&str, String)> = path
+                .split('/')
+                .filter_map(|seg| seg.strip_prefix('{')?.strip_suffix('}'))
+                .map(|p| (p, camel_case(p)))
+                .collect();
+            let mut args: Vec<String> = path_params
+                .iter()
+                .map(|(_, ident)| format!("{}: string | number", ident))
+                .collect();
+
+            // Schema names are referenced through the `T` namespace import
+            let body_type = operation
+                .pointer("/requestBody/content/application~1json/schema")
+                .map(|schema| ts_type(doc, schema, "T.", 0));
+            if let Some(ref t) = body_type {
+                args.push(format!("body: {}", t));
+            }
+
+            let query = query_params(doc, item, operation);
+            if !query.is_empty() {
+                let fields: Vec<String> = query
+                    .iter()
+                    .map(|(name, required, schema)| {
+                        format!(
+                            "{}{}: {}",
+                            property_key(name),
+                            if *required { "" } else { "?" },
+                            ts_type(doc, schema, "T.", 0)
+                        )
+                    })
+                    .collect();
+                let optional = if query.iter().any(|(_, required, _)| *required) {
+                    ""
+                } else {
+                    "?"
+                };
+                args.push(format!("query{}: {{ {} }}", optional, fields.join("; ")));
+            }
+            args.push("init?: RequestInit".to_string());
+
+            let mut url = path_params
+                .iter()
+                .fold(format!("`{}`", path), |url, (raw, ident)| {
+                    url.replace(
+                        &format!("{{{}}}", raw),
+                        &format!("${{encodeURIComponent({})}}", ident),
+                    )
+                });
+            if !query.is_empty() {
+                url = format!("withQuery({}, query)", url);
+            }
+            let body_arg = if body_type.is_some() {
+                "body"
+            } else {
+                "undefined"
+            };
+            let ret = response_type(doc, operation, "T.");
+
+            operations.push_str(&format!(
+                "\nexport function {}({}): Promise<{}> {{\n  return request(\"{}\", {}, {}, init);\n}}\n",
+                operation_name(method, path, operation),
+                args.join(", "),
+                ret,
+                method.to_uppercase(),
+                url,
+                body_arg
+            ));
+        }
+    }
+
+    if operations.contains("withQuery(") {
+        out.push_str(WITH_QUERY_HELPER);
+    }
+    out.push_str(&operations);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn spec() -> Value {
+        json!({
+            "openapi": "3.0.3",
+            "paths": {
+                "/users/{id}": {
+                    "get": {
+                        "operationId": "getUser",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": { "$ref": "#/components/schemas/User" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                "/users": {
+                    "post": {
+                        "operationId": "create_user",
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": { "$ref": "#/components/schemas/NewUser" }
+                                }
+                            }
+                        },
+                        "responses": { "201": { "$ref": "#/components/responses/UserCreated" } }
+                    }
+                }
+            },
+            "components": {
+                "responses": {
+                    "UserCreated": {
+                        "content": {
+                            "application/json": {
+                                "schema": { "$ref": "#/components/schemas/User" }
+                            }
+                        }
+                    }
+                },
+                "schemas": {
+                    "NewUser": {
+                        "type": "object",
+                        "required": ["email"],
+                        "properties": {
+                            "email": { "type": "string" },
+                            "role": { "type": "string", "enum": ["admin", "member"] }
+                        }
+                    },
+                    "User": {
+                        "allOf": [
+                            { "$ref": "#/components/schemas/NewUser" },
+                            {
+                                "type": "object",
+                                "required": ["id"],
+                                "properties": {
+                                    "id": { "type": "integer" },
+                                    "tags": { "type": "array", "items": { "type": "string" } },
+                                    "manager": { "$ref": "#/components/schemas/User", "nullable": true }
+                                }
+                            }
+                        ]
+                    },
+                    "Pet": {
+                        "oneOf": [
+                            { "$ref": "#/components/schemas/Cat" },
+                            { "$ref": "#/components/schemas/Dog" }
+                        ]
+                    },
+                    "Cat": { "type": "object", "properties": { "meow": { "type": "boolean" } } },
+                    "Dog": { "type": "object", "properties": { "bark": { "type": "boolean" } } }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_render_types() {
+        let ts = render_types(&spec());
+
+        assert!(ts.contains(
+            "export interface NewUser {\n  email: string;\n  role?: \"admin\" | \"member\";\n}"
+        ));
+        assert!(ts.contains("export type User = NewUser & {\n"));
+        assert!(ts.contains("  id: number;\n"));
+        assert!(ts.contains("  tags?: string[];\n"));
+        assert!(ts.contains("  manager?: User;\n"));
+        assert!(ts.contains("export type Pet = Cat | Dog;"));
+    }
+
+    #[test]
+    fn test_render_client() {
+        let client = render_client(&spec());
+
+        assert!(client.contains(
+            "export function getUser(id: string | number, init?: RequestInit): Promise<T.User> {\n  return request(\"GET\", `/users/${encodeURIComponent(id)}`, undefined, init);\n}"
+        ));
+        // Response resolved through #/components/responses
+        assert!(client.contains(
+            "export function createUser(body: T.NewUser, init?: RequestInit): Promise<T.User>"
+        ));
+    }
+
+    #[test]
+    fn test_property_key_quotes_invalid_identifiers() {
+        assert_eq!(property_key("name"), "name");
+        assert_eq!(property_key("content-type"), "\"content-type\"");
+        assert_eq!(property_key("2fa"), "\"2fa\"");
+    }
+
+    #[test]
+    fn test_client_qualifies_only_schema_references() {
+        let doc = json!({
+            "openapi": "3.0.3",
+            "paths": {
+                "/orgs/{org-id}/pets": {
+                    "parameters": [
+                        { "name": "org-id", "in": "path", "required": true, "schema": { "type": "string" } }
+                    ],
+                    "get": {
+                        "operationId": "listPets",
+                        "parameters": [
+                            { "name": "kind", "in": "query", "schema": { "type": "string", "enum": ["Cat", "Dog"] } },
+                            { "$ref": "#/components/parameters/Limit" }
+                        ],
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "type": "object",
+                                            "required": ["Cat"],
+                                            "properties": {
+                                                "Cat": { "type": "string" },
+                                                "items": { "type": "array", "items": { "$ref": "#/components/schemas/Cat" } }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "parameters": {
+                    "Limit": { "name": "page-size", "in": "query", "required": true, "schema": { "type": "integer" } }
+                },
+                "schemas": {
+                    "Cat": { "type": "object", "properties": { "meow": { "type": "boolean" } } }
+                }
+            }
+        });
+        let client = render_client(&doc);
+
+        assert!(client.contains(
+            "export function listPets(orgId: string | number, query: { kind?: \"Cat\" | \"Dog\"; \"page-size\": number }, init?: RequestInit)"
+        ));
+        // Property keys and enum members stay as written; only the $ref is qualified
+        assert!(client.contains("\n  Cat: string;\n"));
+        assert!(client.contains("\n  items?: T.Cat[];\n"));
+        assert!(client.contains(
+            "return request(\"GET\", withQuery(`/orgs/${encodeURIComponent(orgId)}/pets`, query), undefined, init);"
+        ));
+        assert!(client.contains(
+            "function withQuery(path: string, query?: Record<string, unknown>): string {"
+        ));
+        assert!(client.contains("if (item !== undefined && item !== null)"));
+        assert!(client.contains("export function setBaseUrl(url: string): void {"));
+        assert!(!client.contains("export let baseUrl"));
+    }
+
+    #[test]
+    fn test_camel_case_path_params() {
+        assert_eq!(camel_case("user-id"), "userId");
+        assert_eq!(camel_case("id"), "id");
+        assert_eq!(camel_case("2fa"), "_2fa");
+    }
+}
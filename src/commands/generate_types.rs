@@ -1,19 +1,92 @@
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use colored::Colorize;
+use std::collections::BTreeSet;
+use std::collections::hash_map::DefaultHasher;
 use std::fs;
+use std::hash::{Hash, Hasher};
 use std::path::Path;
 use std::process::Command;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::sleep;
+use std::time::{Duration, Instant, SystemTime};
 
-/// Generate TypeScript types from Supabase PostgreSQL schema
-pub fn run(host: &str, port: &str, database: &str, output: &str) -> Result<()> {
+/// How often `--watch` checks the schema
+const WATCH_INTERVAL: Duration = Duration::from_secs(2);
+/// How long the schema must stay unchanged before regenerating
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(500);
+/// Granularity at which `--watch` notices Ctrl-C while waiting
+const STOP_CHECK: Duration = Duration::from_millis(100);
+/// Migrations watched for the Supabase (Postgres) engine
+const MIGRATIONS_DIR: &str = "supabase/migrations";
+
+/// Database connection parameters for type generation
+pub struct Connection {
+    pub engine: Engine,
+    pub host: String,
+    pub port: String,
+    pub database: String,
+    pub user: String,
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Engine {
+    Postgres,
+    Mysql,
+}
+
+impl Connection {
+    /// Fill in per-engine defaults for anything not given on the command line
+    pub fn resolve(
+        engine: &str,
+        host: String,
+        port: Option<String>,
+        database: Option<String>,
+        user: Option<String>,
+        password: Option<String>,
+    ) -> Result<Self> {
+        let conn = match engine {
+            "postgres" => Connection {
+                engine: Engine::Postgres,
+                host,
+                port: port.unwrap_or_else(|| "54322".to_string()),
+                database: database.unwrap_or_else(|| "postgres".to_string()),
+                user: user.unwrap_or_else(|| "postgres".to_string()),
+                password: password.or_else(|| Some("postgres".to_string())),
+            },
+            "mysql" => Connection {
+                engine: Engine::Mysql,
+                host,
+                port: port.unwrap_or_else(|| "3306".to_string()),
+                database: database.context("--database is required for --engine mysql")?,
+                user: user.unwrap_or_else(|| "root".to_string()),
+                password,
+            },
+            other => bail!("Unknown engine '{}'. Use 'postgres' or 'mysql'.", other),
+        };
+        Ok(conn)
+    }
+}
+
+/// Generate TypeScript types from a PostgreSQL (Supabase) or MySQL/MariaDB schema
+///
+/// With `zod`, a `<table>.schema.ts` Zod schema is written next to the types.
+/// For Postgres the Supabase CLI only emits types, so the Zod schemas come from
+/// introspecting `information_schema.columns` inside the Supabase db container.
+pub fn run(conn: &Connection, output: &str, zod: bool) -> Result<()> {
+    let source = match conn.engine {
+        Engine::Postgres => "Supabase",
+        Engine::Mysql => "MySQL",
+    };
     println!(
         "{}",
-        "🔧 Generating TypeScript types from Supabase..."
+        format!("🔧 Generating TypeScript types from {}...", source)
             .cyan()
             .bold()
     );
-    println!("   {} Host: {}:{}", "📍".dimmed(), host, port);
-    println!("   {} Database: {}", "💾".dimmed(), database);
+    println!("   {} Host: {}:{}", "📍".dimmed(), conn.host, conn.port);
+    println!("   {} Database: {}", "💾".dimmed(), conn.database);
     println!("   {} Output: {}", "📂".dimmed(), output);
     println!();
 
@@ -25,6 +98,34 @@ pub fn run(host: &str, port: &str, database: &str, output: &str) -> Result<()> {
             .with_context(|| format!("Failed to create directory: {}", output))?;
     }
 
+    match conn.engine {
+        Engine::Postgres => {
+            generate_postgres(conn)?;
+            if zod {
+                write_zod(conn.engine, &introspect_postgres(conn)?, output_path)?;
+            }
+        }
+        Engine::Mysql => generate_mysql(conn, output_path, zod)?,
+    }
+
+    println!();
+    println!(
+        "{}",
+        "✅ TypeScript types generated successfully!".green().bold()
+    );
+    println!();
+    println!("{}", "📝 Next steps:".bright_yellow());
+    println!("  1. Check generated types in {}", output);
+    println!("  2. Import types in your application");
+    println!("  3. Run `airis gen` to update workspace configuration");
+
+    Ok(())
+}
+
+/// Supabase PostgreSQL: delegate to the Supabase CLI
+fn generate_postgres(conn: &Connection) -> Result<()> {
+    crate::docker::ensure_available()?;
+
     // Check if Supabase is running
     println!("   {} Checking if Supabase is running...", "🔍".dimmed());
     let pg_ready = Command::new("docker")
@@ -67,8 +168,12 @@ pub fn run(host: &str, port: &str, database: &str, output: &str) -> Result<()> {
             "typescript",
             "--db-url",
             &format!(
-                "postgresql://postgres:postgres@{}:{}/{}",
-                host, port, database
+                "postgresql://{}:{}@{}:{}/{}",
+                conn.user,
+                conn.password.as_deref().unwrap_or_default(),
+                conn.host,
+                conn.port,
+                conn.database
             ),
         ])
         .current_dir(".")
@@ -79,16 +184,675 @@ pub fn run(host: &str, port: &str, database: &str, output: &str) -> Result<()> {
         anyhow::bail!("Supabase type generation failed");
     }
 
+    Ok(())
+}
+
+/// Regenerate types whenever the schema changes, until interrupted (Ctrl-C).
+///
+/// MySQL polls `information_schema`; Postgres watches `supabase/migrations/`.
+pub fn watch(conn: &Connection, output: &str, zod: bool) -> Result<()> {
+    if conn.engine == Engine::Postgres && !Path::new(MIGRATIONS_DIR).is_dir() {
+        bail!(
+            "--watch with --engine postgres needs {} to exist",
+            MIGRATIONS_DIR
+        );
+    }
+
+    let stop = stop_on_ctrl_c()?;
+    run(conn, output, zod)?;
+    let mut last = schema_snapshot(conn)?;
     println!();
     println!(
         "{}",
-        "✅ TypeScript types generated successfully!".green().bold()
+        "👀 Watching for schema changes (Ctrl-C to stop)...".bright_blue()
     );
+
+    'watch: loop {
+        if wait(WATCH_INTERVAL, &stop) {
+            break;
+        }
+        let mut current = match schema_snapshot(conn) {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                eprintln!("   {} {}", "⚠️".yellow(), e);
+                continue;
+            }
+        };
+        if current.fingerprint == last.fingerprint {
+            continue;
+        }
+
+        // Debounce: wait until a burst of migrations/DDL settles
+        loop {
+            if wait(WATCH_DEBOUNCE, &stop) {
+                break 'watch;
+            }
+            match schema_snapshot(conn) {
+                Ok(next) if next == current => break,
+                Ok(next) => current = next,
+                // Transient (e.g. database restarting mid-migration): keep waiting
+                Err(e) => eprintln!("   {} {}", "⚠️".yellow(), e),
+            }
+        }
+
+        println!();
+        println!("{}", "🔄 Schema changed:".bright_blue());
+        for line in describe_changes(&last, &current) {
+            println!("   {}", line);
+        }
+        if let Err(e) = run(conn, output, zod) {
+            eprintln!("   {} {}", "❌".red(), e);
+        }
+        last = current;
+    }
+
     println!();
-    println!("{}", "📝 Next steps:".bright_yellow());
-    println!("  1. Check generated types in {}", output);
-    println!("  2. Import types in your application");
-    println!("  3. Run `airis gen` to update workspace configuration");
+    println!("{}", "👋 Stopped watching".dimmed());
+    Ok(())
+}
+
+/// Flag set once Ctrl-C is received, so `--watch` can finish cleanly
+fn stop_on_ctrl_c() -> Result<Arc<AtomicBool>> {
+    let stop = Arc::new(AtomicBool::new(false));
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_io()
+        .build()
+        .context("Failed to start the Ctrl-C handler")?;
+    let flag = Arc::clone(&stop);
+    std::thread::spawn(move || {
+        runtime.block_on(async {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                flag.store(true, Ordering::SeqCst);
+            }
+        })
+    });
+    Ok(stop)
+}
+
+/// Sleep for `duration`, returning early with `true` once `stop` is set
+fn wait(duration: Duration, stop: &AtomicBool) -> bool {
+    let deadline = Instant::now() + duration;
+    while !stop.load(Ordering::SeqCst) {
+        let now = Instant::now();
+        if now >= deadline {
+            return false;
+        }
+        sleep(STOP_CHECK.min(deadline - now));
+    }
+    true
+}
+
+/// What the generated types are derived from, for `--watch` change detection
+#[derive(Debug, Clone, PartialEq, Default)]
+struct SchemaSnapshot {
+    /// Table names (mysql) or migration file names (postgres)
+    items: BTreeSet<String>,
+    fingerprint: u64,
+}
+
+fn schema_snapshot(conn: &Connection) -> Result<SchemaSnapshot> {
+    match conn.engine {
+        Engine::Mysql => Ok(columns_snapshot(&introspect_mysql(conn)?)),
+        Engine::Postgres => migrations_snapshot(Path::new(MIGRATIONS_DIR)),
+    }
+}
+
+fn columns_snapshot(columns: &[Column]) -> SchemaSnapshot {
+    let mut hasher = DefaultHasher::new();
+    columns.hash(&mut hasher);
+    SchemaSnapshot {
+        items: columns.iter().map(|c| c.table.clone()).collect(),
+        fingerprint: hasher.finish(),
+    }
+}
+
+fn migrations_snapshot(dir: &Path) -> Result<SchemaSnapshot> {
+    let mut entries: Vec<(String, u64, Option<SystemTime>)> = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))? {
+        let entry = entry?;
+        let meta = entry.metadata()?;
+        if meta.is_file() {
+            entries.push((
+                entry.file_name().to_string_lossy().into_owned(),
+                meta.len(),
+                meta.modified().ok(),
+            ));
+        }
+    }
+    entries.sort();
+
+    let mut hasher = DefaultHasher::new();
+    entries.hash(&mut hasher);
+    Ok(SchemaSnapshot {
+        items: entries.into_iter().map(|(name, _, _)| name).collect(),
+        fingerprint: hasher.finish(),
+    })
+}
+
+/// Short human-readable diff between two snapshots
+fn describe_changes(old: &SchemaSnapshot, new: &SchemaSnapshot) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for added in new.items.difference(&old.items) {
+        lines.push(format!("{} {}", "+".green(), added));
+    }
+    for removed in old.items.difference(&new.items) {
+        lines.push(format!("{} {}", "-".red(), removed));
+    }
+    if lines.is_empty() {
+        lines.push(format!("{} columns changed", "~".yellow()));
+    }
+    lines
+}
+
+/// One row of `information_schema.columns`
+///
+/// For Postgres `data_type` holds the `udt_name` (`int4`, `_text`, ...) and
+/// `column_type` the quoted labels of an enum type, if any.
+#[derive(Debug, Clone, PartialEq, Hash)]
+struct Column {
+    table: String,
+    column: String,
+    data_type: String,
+    column_type: String,
+    nullable: bool,
+}
+
+impl Column {
+    fn kind(&self, engine: Engine) -> ColumnKind {
+        match engine {
+            Engine::Mysql => mysql_column_kind(&self.data_type, &self.column_type),
+            Engine::Postgres => postgres_column_kind(&self.data_type, &self.column_type),
+        }
+    }
+}
+
+/// MySQL/MariaDB: introspect `information_schema.columns` via the mysql client
+fn generate_mysql(conn: &Connection, output_path: &Path, zod: bool) -> Result<()> {
+    println!(
+        "   {} Introspecting schema with mysql client...",
+        "⚙️".dimmed()
+    );
+
+    let columns = introspect_mysql(conn)?;
+    if columns.is_empty() {
+        bail!("No tables found in database '{}'", conn.database);
+    }
+
+    let target = output_path.join("database.types.ts");
+    fs::write(&target, render_mysql_types(&columns))
+        .with_context(|| format!("Failed to write {}", target.display()))?;
+    println!("   {} Wrote {}", "✅".green(), target.display());
+
+    if zod {
+        write_zod(conn.engine, &columns, output_path)?;
+    }
 
     Ok(())
 }
+
+/// Write one `<table>.schema.ts` per table into `output_path`
+fn write_zod(engine: Engine, columns: &[Column], output_path: &Path) -> Result<()> {
+    for (file, content) in render_zod(engine, columns) {
+        let target = output_path.join(file);
+        fs::write(&target, content)
+            .with_context(|| format!("Failed to write {}", target.display()))?;
+        println!("   {} Wrote {}", "✅".green(), target.display());
+    }
+    Ok(())
+}
+
+/// Query column metadata for the connection's database
+fn introspect_mysql(conn: &Connection) -> Result<Vec<Column>> {
+    let query = "SELECT TABLE_NAME, COLUMN_NAME, DATA_TYPE, COLUMN_TYPE, IS_NULLABLE \
+                 FROM information_schema.columns WHERE TABLE_SCHEMA = DATABASE() \
+                 ORDER BY TABLE_NAME, ORDINAL_POSITION";
+
+    let mut cmd = Command::new("mysql");
+    cmd.args([
+        "--batch",
+        "--skip-column-names",
+        "-h",
+        &conn.host,
+        "-P",
+        &conn.port,
+        "-u",
+        &conn.user,
+        "-D",
+        &conn.database,
+        "-e",
+        query,
+    ]);
+    // Pass the password via env so it doesn't show up in the process list
+    if let Some(password) = &conn.password {
+        cmd.env("MYSQL_PWD", password);
+    }
+
+    let out = cmd
+        .output()
+        .with_context(|| "Failed to run mysql client (is it installed?)")?;
+    if !out.status.success() {
+        bail!(
+            "MySQL schema introspection failed: {}",
+            String::from_utf8_lossy(&out.stderr).trim()
+        );
+    }
+
+    Ok(parse_columns(&String::from_utf8_lossy(&out.stdout)))
+}
+
+/// Query column metadata of the `public` schema via psql in the Supabase db container
+fn introspect_postgres(conn: &Connection) -> Result<Vec<Column>> {
+    println!(
+        "   {} Introspecting schema for Zod with psql...",
+        "⚙️".dimmed()
+    );
+
+    let query = "SELECT c.table_name, c.column_name, c.udt_name, \
+                 COALESCE((SELECT string_agg(quote_literal(e.enumlabel), ',' ORDER BY e.enumsortorder) \
+                 FROM pg_type t JOIN pg_enum e ON e.enumtypid = t.oid WHERE t.typname = c.udt_name), ''), \
+                 c.is_nullable \
+                 FROM information_schema.columns c WHERE c.table_schema = 'public' \
+                 ORDER BY c.table_name, c.ordinal_position";
+
+    let out = Command::new("docker")
+        .args([
+            "compose",
+            "-f",
+            "supabase/docker-compose.yml",
+            "exec",
+            "-T",
+            "db",
+            "psql",
+            "-U",
+            &conn.user,
+            "-d",
+            &conn.database,
+            "-At",
+            "-F",
+            "\t",
+            "-c",
+            query,
+        ])
+        .output()
+        .with_context(|| "Failed to run psql in the Supabase db container")?;
+    if !out.status.success() {
+        bail!(
+            "Postgres schema introspection failed: {}",
+            String::from_utf8_lossy(&out.stderr).trim()
+        );
+    }
+
+    let columns = parse_columns(&String::from_utf8_lossy(&out.stdout));
+    if columns.is_empty() {
+        bail!("No tables found in schema 'public' of '{}'", conn.database);
+    }
+    Ok(columns)
+}
+
+/// Parse tab-separated `table, column, type, column type, nullable` rows
+/// (`mysql --batch --skip-column-names` / `psql -At -F '\t'`)
+fn parse_columns(stdout: &str) -> Vec<Column> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let mut f = line.split('\t');
+            Some(Column {
+                table: f.next()?.to_string(),
+                column: f.next()?.to_string(),
+                data_type: f.next()?.to_lowercase(),
+                column_type: f.next()?.to_string(),
+                nullable: f.next()? == "YES",
+            })
+        })
+        .collect()
+}
+
+/// Language-neutral column type, shared by the TypeScript and Zod renderers
+/// so the two outputs can't drift.
+#[derive(Debug, Clone, PartialEq)]
+enum ColumnKind {
+    Boolean,
+    Number,
+    /// Textual values, including dates and values too wide for a JS number
+    Text,
+    Json,
+    Bytes,
+    Enum(Vec<String>),
+    Array(Box<ColumnKind>),
+}
+
+impl ColumnKind {
+    fn ts(&self) -> String {
+        match self {
+            ColumnKind::Boolean => "boolean".to_string(),
+            ColumnKind::Number => "number".to_string(),
+            ColumnKind::Text => "string".to_string(),
+            ColumnKind::Json => "unknown".to_string(),
+            ColumnKind::Bytes => "Uint8Array".to_string(),
+            ColumnKind::Enum(values) => values
+                .iter()
+                .map(|v| format!("\"{}\"", v))
+                .collect::<Vec<_>>()
+                .join(" | "),
+            ColumnKind::Array(inner) => match inner.as_ref() {
+                ColumnKind::Enum(_) => format!("({})[]", inner.ts()),
+                _ => format!("{}[]", inner.ts()),
+            },
+        }
+    }
+
+    fn zod(&self) -> String {
+        match self {
+            ColumnKind::Boolean => "z.boolean()".to_string(),
+            ColumnKind::Number => "z.number()".to_string(),
+            ColumnKind::Text => "z.string()".to_string(),
+            ColumnKind::Json => "z.unknown()".to_string(),
+            ColumnKind::Bytes => "z.instanceof(Uint8Array)".to_string(),
+            ColumnKind::Enum(values) => format!(
+                "z.enum([{}])",
+                values
+                    .iter()
+                    .map(|v| format!("\"{}\"", v))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            ColumnKind::Array(inner) => format!("z.array({})", inner.zod()),
+        }
+    }
+}
+
+/// Parse a quoted, comma-separated value list (`'a','b'`) into enum labels
+fn enum_labels(list: &str) -> Option<ColumnKind> {
+    let values: Vec<String> = list
+        .split(',')
+        .map(|v| v.trim().trim_matches('\'').to_string())
+        .filter(|v| !v.is_empty())
+        .collect();
+    if values.is_empty() {
+        None
+    } else {
+        Some(ColumnKind::Enum(values))
+    }
+}
+
+/// Classify a MySQL column
+fn mysql_column_kind(data_type: &str, column_type: &str) -> ColumnKind {
+    match data_type {
+        // tinyint(1) is MySQL's boolean
+        "tinyint" if column_type.starts_with("tinyint(1)") => ColumnKind::Boolean,
+        "tinyint" | "smallint" | "mediumint" | "int" | "integer" | "float" | "double" | "real"
+        | "year" => ColumnKind::Number,
+        // Exceed JS number precision; drivers return strings
+        "bigint" | "decimal" | "numeric" => ColumnKind::Text,
+        "bit" if column_type == "bit(1)" => ColumnKind::Boolean,
+        "json" => ColumnKind::Json,
+        "enum" => column_type
+            .split_once('(')
+            .and_then(|(_, rest)| rest.strip_suffix(')'))
+            .and_then(enum_labels)
+            .unwrap_or(ColumnKind::Text),
+        "binary" | "varbinary" | "blob" | "tinyblob" | "mediumblob" | "longblob" | "bit" => {
+            ColumnKind::Bytes
+        }
+        // set, date/time types, char/text types
+        _ => ColumnKind::Text,
+    }
+}
+
+/// Classify a Postgres column by its `udt_name`; `enum_values` holds the
+/// quoted labels when the type is an enum
+fn postgres_column_kind(udt_name: &str, enum_values: &str) -> ColumnKind {
+    if let Some(element) = udt_name.strip_prefix('_') {
+        return ColumnKind::Array(Box::new(postgres_column_kind(element, "")));
+    }
+    if let Some(kind) = enum_labels(enum_values) {
+        return kind;
+    }
+    match udt_name {
+        "bool" => ColumnKind::Boolean,
+        "int2" | "int4" | "float4" | "float8" | "oid" => ColumnKind::Number,
+        // Exceed JS number precision; drivers return strings
+        "int8" | "numeric" | "money" => ColumnKind::Text,
+        "json" | "jsonb" => ColumnKind::Json,
+        "bytea" => ColumnKind::Bytes,
+        // uuid, text/varchar, date/time types, ...
+        _ => ColumnKind::Text,
+    }
+}
+
+/// Convert snake_case / kebab-case table names to PascalCase interface names
+fn pascal_case(name: &str) -> String {
+    name.split(['_', '-'])
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let mut chars = s.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Group columns by table, preserving query order
+fn tables(columns: &[Column]) -> Vec<(&str, Vec<&Column>)> {
+    let mut tables: Vec<(&str, Vec<&Column>)> = Vec::new();
+    for col in columns {
+        match tables.last_mut() {
+            Some((table, cols)) if *table == col.table => cols.push(col),
+            _ => tables.push((col.table.as_str(), vec![col])),
+        }
+    }
+    tables
+}
+
+/// Render one exported interface per table, in table order
+fn render_mysql_types(columns: &[Column]) -> String {
+    let mut out = String::from(
+        "// Generated by `airis generate types --engine mysql`. Do not edit manually.\n",
+    );
+
+    for (table, cols) in tables(columns) {
+        out.push_str(&format!("\nexport interface {} {{\n", pascal_case(table)));
+        for col in cols {
+            let ts = col.kind(Engine::Mysql).ts();
+            let ts = if col.nullable {
+                format!("{} | null", ts)
+            } else {
+                ts
+            };
+            out.push_str(&format!("  {}: {};\n", col.column, ts));
+        }
+        out.push_str("}\n");
+    }
+
+    out
+}
+
+/// Render `<table>.schema.ts` files exporting a Zod object per table
+fn render_zod(engine: Engine, columns: &[Column]) -> Vec<(String, String)> {
+    tables(columns)
+        .into_iter()
+        .map(|(table, cols)| {
+            let mut out = String::from(
+                "// Generated by `airis generate types --zod`. Do not edit manually.\n\
+                 import { z } from \"zod\";\n\n",
+            );
+            let name = pascal_case(table);
+            out.push_str(&format!("export const {}Schema = z.object({{\n", name));
+            for col in cols {
+                let zod = col.kind(engine).zod();
+                let zod = if col.nullable {
+                    format!("{}.nullable()", zod)
+                } else {
+                    zod
+                };
+                out.push_str(&format!("  {}: {},\n", col.column, zod));
+            }
+            out.push_str("});\n\n");
+            out.push_str(&format!(
+                "export type {name} = z.infer<typeof {name}Schema>;\n"
+            ));
+            (format!("{}.schema.ts", table), out)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts(data_type: &str, column_type: &str) -> String {
+        mysql_column_kind(data_type, column_type).ts()
+    }
+
+    #[test]
+    fn test_mysql_column_kind_ts() {
+        assert_eq!(ts("tinyint", "tinyint(1)"), "boolean");
+        assert_eq!(ts("int", "int(11) unsigned"), "number");
+        assert_eq!(ts("bigint", "bigint(20)"), "string");
+        assert_eq!(ts("varchar", "varchar(255)"), "string");
+        assert_eq!(ts("json", "json"), "unknown");
+        assert_eq!(
+            ts("enum", "enum('draft','published')"),
+            "\"draft\" | \"published\""
+        );
+    }
+
+    #[test]
+    fn test_render_mysql_zod() {
+        let columns = parse_columns(
+            "posts\tid\tint\tint(11)\tNO\n\
+             posts\tstatus\tenum\tenum('draft','published')\tNO\n\
+             posts\tbody\ttext\ttext\tYES\n",
+        );
+
+        let files = render_zod(Engine::Mysql, &columns);
+        assert_eq!(files.len(), 1);
+        let (name, content) = &files[0];
+        assert_eq!(name, "posts.schema.ts");
+        assert!(content.contains("export const PostsSchema = z.object({"));
+        assert!(content.contains("  id: z.number(),"));
+        assert!(content.contains("  status: z.enum([\"draft\", \"published\"]),"));
+        assert!(content.contains("  body: z.string().nullable(),"));
+        assert!(content.contains("export type Posts = z.infer<typeof PostsSchema>;"));
+    }
+
+    #[test]
+    fn test_render_postgres_zod() {
+        // psql -At output: enum labels in the 4th field, arrays as _<element>
+        let columns = parse_columns(
+            "profiles\tid\tuuid\t\tNO\n\
+             profiles\tage\tint4\t\tYES\n\
+             profiles\tmood\tmood\t'Happy','sad'\tNO\n\
+             profiles\ttags\t_text\t\tNO\n\
+             profiles\tmeta\tjsonb\t\tYES\n",
+        );
+        assert_eq!(columns.len(), 5);
+
+        let files = render_zod(Engine::Postgres, &columns);
+        assert_eq!(files.len(), 1);
+        let (name, content) = &files[0];
+        assert_eq!(name, "profiles.schema.ts");
+        assert!(content.contains("export const ProfilesSchema = z.object({"));
+        assert!(content.contains("  id: z.string(),"));
+        assert!(content.contains("  age: z.number().nullable(),"));
+        assert!(content.contains("  mood: z.enum([\"Happy\", \"sad\"]),"));
+        assert!(content.contains("  tags: z.array(z.string()),"));
+        assert!(content.contains("  meta: z.unknown().nullable(),"));
+    }
+
+    #[test]
+    fn test_postgres_column_kind_ts() {
+        let ts = |udt: &str, labels: &str| postgres_column_kind(udt, labels).ts();
+        assert_eq!(ts("bool", ""), "boolean");
+        assert_eq!(ts("int8", ""), "string");
+        assert_eq!(ts("float8", ""), "number");
+        assert_eq!(ts("_int4", ""), "number[]");
+        assert_eq!(ts("status", "'a','b'"), "\"a\" | \"b\"");
+    }
+
+    #[test]
+    fn test_render_mysql_types() {
+        let columns = parse_columns(
+            "blog_posts\tid\tint\tint(11)\tNO\n\
+             blog_posts\ttitle\tvarchar\tvarchar(255)\tYES\n\
+             users\tactive\ttinyint\ttinyint(1)\tNO\n",
+        );
+        assert_eq!(columns.len(), 3);
+
+        let ts = render_mysql_types(&columns);
+        assert!(
+            ts.contains("export interface BlogPosts {\n  id: number;\n  title: string | null;\n}")
+        );
+        assert!(ts.contains("export interface Users {\n  active: boolean;\n}"));
+    }
+
+    #[test]
+    fn test_describe_changes() {
+        let before = columns_snapshot(&parse_columns(
+            "posts\tid\tint\tint(11)\tNO\nusers\tid\tint\tint(11)\tNO\n",
+        ));
+        let after = columns_snapshot(&parse_columns(
+            "comments\tid\tint\tint(11)\tNO\nusers\tid\tint\tint(11)\tNO\n",
+        ));
+        assert_ne!(before.fingerprint, after.fingerprint);
+
+        let lines = describe_changes(&before, &after);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with("comments"));
+        assert!(lines[1].ends_with("posts"));
+
+        let widened = columns_snapshot(&parse_columns(
+            "posts\tid\tbigint\tbigint(20)\tNO\nusers\tid\tint\tint(11)\tNO\n",
+        ));
+        assert!(describe_changes(&before, &widened)[0].ends_with("columns changed"));
+    }
+
+    #[test]
+    fn test_migrations_snapshot_tracks_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("001_init.sql"), "create table a();").unwrap();
+        let before = migrations_snapshot(dir.path()).unwrap();
+
+        fs::write(dir.path().join("002_b.sql"), "create table b();").unwrap();
+        let after = migrations_snapshot(dir.path()).unwrap();
+
+        assert_ne!(before.fingerprint, after.fingerprint);
+        assert!(after.items.contains("002_b.sql"));
+    }
+
+    #[test]
+    fn test_wait_returns_early_when_stopped() {
+        let stop = AtomicBool::new(false);
+        assert!(!wait(Duration::from_millis(10), &stop));
+
+        stop.store(true, Ordering::SeqCst);
+        let started = Instant::now();
+        assert!(wait(Duration::from_secs(60), &stop));
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_connection_defaults() {
+        let pg =
+            Connection::resolve("postgres", "localhost".into(), None, None, None, None).unwrap();
+        assert_eq!(pg.port, "54322");
+        assert_eq!(pg.database, "postgres");
+
+        let my = Connection::resolve(
+            "mysql",
+            "localhost".into(),
+            None,
+            Some("app".into()),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(my.engine, Engine::Mysql);
+        assert_eq!(my.port, "3306");
+        assert_eq!(my.user, "root");
+
+        assert!(Connection::resolve("mysql", "localhost".into(), None, None, None, None).is_err());
+    }
+}
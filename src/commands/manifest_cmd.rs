@@ -1,15 +1,34 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use colored::Colorize;
 use indexmap::IndexMap;
 use serde::Serialize;
+use std::fs;
 use std::path::Path;
 
-use crate::manifest::{MANIFEST_FILE, Manifest};
+use crate::error::AirisError;
+use crate::manifest::{
+    MANIFEST_FILE, MANIFEST_SECTIONS, Manifest, set_toml_segments, set_toml_value,
+};
+use crate::safe_fs::SafeFS;
 
 pub enum ManifestAction {
     DevApps,
-    Rule { name: String },
+    Rule {
+        name: String,
+    },
     Json,
+    ImportScripts {
+        raw: bool,
+    },
+    Get {
+        path: String,
+        default: Option<String>,
+    },
+    Set {
+        path: String,
+        value: String,
+        json: bool,
+    },
 }
 
 /// Workspace truth output for LLM consumption
@@ -24,7 +43,7 @@ pub struct WorkspaceTruth {
     pub workspace_root: String,
     /// List of compose files used by this workspace
     pub compose_files: Vec<String>,
-    /// Full docker compose command with -f flags
+    /// Full docker compose command with -f and --profile flags
     pub compose_command: String,
     /// Primary service name for exec/run
     pub service: String,
@@ -94,13 +113,18 @@ impl WorkspaceTruth {
         }
 
         // Build compose command
-        let compose_command = if compose_files.is_empty() {
+        let mut compose_command = if compose_files.is_empty() {
             "docker compose".to_string()
         } else {
             let file_args: Vec<String> =
                 compose_files.iter().map(|f| format!("-f {}", f)).collect();
             format!("docker compose {}", file_args.join(" "))
         };
+        if let Some(dev) = &manifest.orchestration.dev {
+            for profile in &dev.profiles {
+                compose_command.push_str(&format!(" --profile {}", profile));
+            }
+        }
 
         // Extract package manager base name
         let pm_full = &manifest.workspace.package_manager;
@@ -162,9 +186,7 @@ impl WorkspaceTruth {
 pub fn run(action: ManifestAction) -> Result<()> {
     let manifest_path = Path::new(MANIFEST_FILE);
     if !manifest_path.exists() {
-        anyhow::bail!(
-            "❌ manifest.toml not found. Create one (see docs/manifest.md) or ask Claude Code to run /airis:init."
-        );
+        return Err(AirisError::ManifestMissing.into());
     }
 
     let manifest = Manifest::load(manifest_path)?;
@@ -200,11 +222,210 @@ pub fn run(action: ManifestAction) -> Result<()> {
             let truth = WorkspaceTruth::from_manifest(&manifest)?;
             println!("{}", truth.to_json()?);
         }
+        ManifestAction::ImportScripts { raw } => import_scripts(&manifest, raw)?,
+        ManifestAction::Get { path, default } => {
+            let content = fs::read_to_string(manifest_path)
+                .with_context(|| format!("Failed to read {}", MANIFEST_FILE))?;
+            let value: toml::Value =
+                toml::from_str(&content).context("Failed to parse manifest.toml")?;
+            match (lookup_toml_path(&value, &path), default) {
+                (Some(found), _) => println!("{}", render_toml_value(found)?),
+                (None, Some(default)) => println!("{}", default),
+                (None, None) => anyhow::bail!("`{}` is not set in manifest.toml", path),
+            }
+        }
+        ManifestAction::Set { path, value, json } => set_value(&path, &value, json)?,
+    }
+
+    Ok(())
+}
+
+/// Resolve a dotted path (`workspace.package_manager`, `app.0.name`) in the
+/// manifest as written. Numeric segments index into arrays.
+fn lookup_toml_path<'a>(value: &'a toml::Value, path: &str) -> Option<&'a toml::Value> {
+    path.split('.')
+        .try_fold(value, |current, segment| match current {
+            toml::Value::Table(table) => table.get(segment),
+            toml::Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+            _ => None,
+        })
+}
+
+/// Update one value in manifest.toml without disturbing the rest of the file.
+///
+/// Plain values keep the type of the value they replace (a string stays a
+/// string); new values become bool, integer or string by shape. `json`
+/// parses the value as JSON for arrays and tables. The result must still load
+/// as a manifest before it is written through `SafeFS`.
+fn set_value(path: &str, raw: &str, json: bool) -> Result<()> {
+    let section = path.split('.').next().unwrap_or_default();
+    if !MANIFEST_SECTIONS.contains(&section) {
+        anyhow::bail!(
+            "Unknown manifest section `{}` (known: {})",
+            section,
+            MANIFEST_SECTIONS.join(", ")
+        );
+    }
+
+    let content = fs::read_to_string(MANIFEST_FILE)
+        .with_context(|| format!("Failed to read {}", MANIFEST_FILE))?;
+    let current: toml::Value = toml::from_str(&content).context("Failed to parse manifest.toml")?;
+
+    let value = if json {
+        let parsed: serde_json::Value =
+            serde_json::from_str(raw).context("--json value is not valid JSON")?;
+        json_to_toml(&parsed)?
+    } else {
+        coerce_value(raw, lookup_toml_path(&current, path))
+    };
+
+    let updated = set_toml_value(&content, path, value.clone())?;
+    toml::from_str::<Manifest>(&updated)
+        .with_context(|| format!("Refusing to set `{}`: manifest.toml would not load", path))?;
+
+    SafeFS::current(false)?.edit(MANIFEST_FILE, updated)?;
+    println!(
+        "{} {} = {}",
+        "✅".green(),
+        path.bold(),
+        crate::manifest::inline_toml(&value)
+    );
+    Ok(())
+}
+
+/// Infer the TOML type of a command-line value
+fn coerce_value(raw: &str, existing: Option<&toml::Value>) -> toml::Value {
+    if let Some(toml::Value::String(_)) = existing {
+        return toml::Value::String(raw.to_string());
+    }
+    match raw {
+        "true" => toml::Value::Boolean(true),
+        "false" => toml::Value::Boolean(false),
+        _ => raw
+            .parse::<i64>()
+            .map(toml::Value::Integer)
+            .unwrap_or_else(|_| toml::Value::String(raw.to_string())),
+    }
+}
+
+fn json_to_toml(value: &serde_json::Value) -> Result<toml::Value> {
+    Ok(match value {
+        serde_json::Value::Null => anyhow::bail!("TOML has no null; remove the key by hand"),
+        serde_json::Value::Bool(b) => toml::Value::Boolean(*b),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => toml::Value::Integer(i),
+            None => toml::Value::Float(n.as_f64().context("Number out of range")?),
+        },
+        serde_json::Value::String(s) => toml::Value::String(s.clone()),
+        serde_json::Value::Array(items) => {
+            toml::Value::Array(items.iter().map(json_to_toml).collect::<Result<_>>()?)
+        }
+        serde_json::Value::Object(map) => {
+            let mut table = toml::Table::new();
+            for (k, v) in map {
+                table.insert(k.clone(), json_to_toml(v)?);
+            }
+            toml::Value::Table(table)
+        }
+    })
+}
+
+/// Scalars print bare (strings unquoted); arrays and tables print as JSON
+fn render_toml_value(value: &toml::Value) -> Result<String> {
+    Ok(match value {
+        toml::Value::String(s) => s.clone(),
+        toml::Value::Integer(i) => i.to_string(),
+        toml::Value::Float(f) => f.to_string(),
+        toml::Value::Boolean(b) => b.to_string(),
+        toml::Value::Datetime(d) => d.to_string(),
+        toml::Value::Array(_) | toml::Value::Table(_) => serde_json::to_string_pretty(value)?,
+    })
+}
+
+/// Copy root package.json `scripts` into `[commands]`.
+///
+/// Scripts become `<compose> exec <service> <pm> run <name>` unless `raw`,
+/// in which case the script body is kept as plain shell. Existing commands
+/// are never overwritten. The manifest is edited as text (comments survive)
+/// and written through `SafeFS`, so the previous version is backed up.
+fn import_scripts(manifest: &Manifest, raw: bool) -> Result<()> {
+    let package_json =
+        fs::read_to_string("package.json").context("Failed to read root package.json")?;
+    let package: serde_json::Value =
+        serde_json::from_str(&package_json).context("Failed to parse root package.json")?;
+
+    let Some(scripts) = package.get("scripts").and_then(|s| s.as_object()) else {
+        println!("{} package.json has no scripts to import", "⚠️".yellow());
+        return Ok(());
+    };
+
+    let truth = WorkspaceTruth::from_manifest(manifest)?;
+    let service = if truth.service.is_empty() {
+        "workspace"
+    } else {
+        truth.service.as_str()
+    };
+    let pm = if truth.package_manager.is_empty() {
+        "pnpm"
+    } else {
+        truth.package_manager.as_str()
+    };
+
+    let mut entries: Vec<(String, String)> = Vec::new();
+    for (name, body) in scripts {
+        if manifest.commands.contains_key(name) {
+            println!("   {} {} (already in [commands])", "⏭️".dimmed(), name);
+            continue;
+        }
+        let command = if raw {
+            body.as_str().unwrap_or_default().to_string()
+        } else {
+            format!(
+                "{} exec {} {} run {}",
+                truth.compose_command, service, pm, name
+            )
+        };
+        entries.push((name.clone(), command));
+    }
+
+    if entries.is_empty() {
+        println!("{} Nothing to import", "✅".green());
+        return Ok(());
     }
 
+    let content = fs::read_to_string(MANIFEST_FILE)
+        .with_context(|| format!("Failed to read {}", MANIFEST_FILE))?;
+    let updated = insert_commands(&content, &entries)
+        .context("Refusing to import scripts into manifest.toml")?;
+
+    SafeFS::current(false)?.edit(MANIFEST_FILE, updated)?;
+
+    for (name, command) in &entries {
+        println!("   {} {} = {}", "✓".green(), name.bold(), command.dimmed());
+    }
+    println!(
+        "{} Imported {} script(s) into [commands]",
+        "✅".green(),
+        entries.len()
+    );
+
     Ok(())
 }
 
+/// Add `key = "value"` entries to the `[commands]` table, creating the
+/// table if it doesn't exist.
+fn insert_commands(content: &str, entries: &[(String, String)]) -> Result<String> {
+    entries
+        .iter()
+        .try_fold(content.to_string(), |text, (name, command)| {
+            set_toml_segments(
+                &text,
+                &["commands".to_string(), name.clone()],
+                toml::Value::String(command.clone()),
+            )
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -347,4 +568,134 @@ traefik = "traefik/compose.yml"
         std::env::set_current_dir(original_dir).unwrap();
         result.unwrap();
     }
+
+    #[test]
+    fn test_workspace_truth_compose_profiles() {
+        let _guard = DIR_LOCK.lock().unwrap();
+        let manifest_content = r#"
+version = 1
+[project]
+id = "test"
+
+[workspace]
+name = "test"
+
+[orchestration.dev]
+workspace = "compose.yaml"
+profiles = ["full", "mail"]
+"#;
+        let manifest: Manifest = toml::from_str(manifest_content).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let original_dir = std::env::current_dir().unwrap();
+        std::env::set_current_dir(&dir).unwrap();
+
+        let result = std::panic::catch_unwind(|| {
+            let truth = WorkspaceTruth::from_manifest(&manifest).unwrap();
+            assert_eq!(
+                truth.compose_command,
+                "docker compose -f compose.yaml --profile full --profile mail"
+            );
+            assert_eq!(
+                truth.recommended_commands.get("up"),
+                Some(
+                    &"docker compose -f compose.yaml --profile full --profile mail up -d"
+                        .to_string()
+                )
+            );
+        });
+
+        std::env::set_current_dir(original_dir).unwrap();
+        result.unwrap();
+    }
+
+    #[test]
+    fn test_lookup_toml_path() {
+        let value: toml::Value = toml::from_str(
+            r#"
+[workspace]
+package_manager = "pnpm@10.0.0"
+
+[[app]]
+name = "web"
+ports = [3000]
+
+[packages.catalog]
+react = "^19.0.0"
+"#,
+        )
+        .unwrap();
+
+        let get = |path| lookup_toml_path(&value, path).map(|v| render_toml_value(v).unwrap());
+        assert_eq!(
+            get("workspace.package_manager").as_deref(),
+            Some("pnpm@10.0.0")
+        );
+        assert_eq!(get("packages.catalog.react").as_deref(), Some("^19.0.0"));
+        assert_eq!(get("app.0.name").as_deref(), Some("web"));
+        assert_eq!(get("app.0.ports").as_deref(), Some("[\n  3000\n]"));
+        assert_eq!(get("app.1.name"), None);
+        assert_eq!(get("workspace.missing"), None);
+        assert_eq!(get("workspace.package_manager.deeper"), None);
+    }
+
+    #[test]
+    fn test_coerce_value_keeps_string_type() {
+        let existing = toml::Value::String("42".into());
+        assert_eq!(
+            coerce_value("7", Some(&existing)),
+            toml::Value::String("7".into())
+        );
+        assert_eq!(coerce_value("7", None), toml::Value::Integer(7));
+        assert_eq!(coerce_value("true", None), toml::Value::Boolean(true));
+        assert_eq!(
+            coerce_value("acme/demo", None),
+            toml::Value::String("acme/demo".into())
+        );
+    }
+
+    #[test]
+    fn test_json_to_toml() {
+        let json: serde_json::Value = serde_json::from_str(r#"{"a": [1, 2.5], "b": "x"}"#).unwrap();
+        let value = json_to_toml(&json).unwrap();
+        assert_eq!(value["a"][0], toml::Value::Integer(1));
+        assert_eq!(value["a"][1], toml::Value::Float(2.5));
+        assert_eq!(value["b"].as_str(), Some("x"));
+        assert!(json_to_toml(&serde_json::Value::Null).is_err());
+    }
+
+    #[test]
+    fn test_insert_commands_into_existing_table() {
+        let content = "version = 1\n\n[commands]\nup = \"docker compose up -d\"\n\n# Rules\n[rule.verify]\ncommands = []\n";
+        let entries = vec![
+            ("dev".to_string(), "pnpm dev".to_string()),
+            (
+                "build.web".to_string(),
+                "pnpm --filter web build".to_string(),
+            ),
+        ];
+        let updated = insert_commands(content, &entries).unwrap();
+        assert_eq!(
+            updated,
+            "version = 1\n\n[commands]\nup = \"docker compose up -d\"\ndev = \"pnpm dev\"\n\"build.web\" = \"pnpm --filter web build\"\n\n# Rules\n[rule.verify]\ncommands = []\n"
+        );
+
+        let manifest: toml::Value = toml::from_str(&updated).unwrap();
+        assert_eq!(
+            manifest["commands"]["build.web"].as_str(),
+            Some("pnpm --filter web build")
+        );
+    }
+
+    #[test]
+    fn test_insert_commands_creates_table() {
+        let entries = vec![("lint".to_string(), "eslint \"src/**\"".to_string())];
+        let updated = insert_commands("version = 1", &entries).unwrap();
+        let manifest: toml::Value = toml::from_str(&updated).unwrap();
+        assert_eq!(
+            manifest["commands"]["lint"].as_str(),
+            Some("eslint \"src/**\"")
+        );
+        assert!(updated.starts_with("version = 1\n\n[commands]\n"));
+    }
 }
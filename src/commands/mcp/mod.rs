@@ -443,7 +443,13 @@ fn handle_manifest_apply(arguments: &Value) -> Result<Value> {
     if run_gen {
         // Load the manifest we just wrote to ensure we're using the latest
         let _manifest = Manifest::load(Path::new("manifest.toml"))?;
-        crate::commands::generate::run(false, false, false)?;
+        crate::commands::generate::run(
+            false,
+            false,
+            false,
+            false,
+            &crate::commands::generate::GenFilter::default(),
+        )?;
         response_text.push_str("\nEnvironment updated with 'airis workspace gen'.");
     } else {
         response_text.push_str("\nRun 'airis workspace gen' to update the environment.");
@@ -471,6 +477,8 @@ fn handle_migration_execute(arguments: &Value) -> Result<Value> {
             libs: vec![],
             compose_files: vec![],
             catalog: indexmap::IndexMap::new(),
+            package_manager: None,
+            compose_service: None,
         },
     };
 
@@ -5,6 +5,33 @@ use indexmap::IndexMap;
 
 use crate::commands::discover::{ComposeLocation, DiscoveryResult};
 
+/// Package manager used when package.json has no `packageManager` field
+const DEFAULT_PACKAGE_MANAGER: &str = "pnpm";
+
+/// Compose service used when no root/workspace compose file names one
+const DEFAULT_SERVICE: &str = "node";
+
+/// Package manager binary from discovery ("bun@1.1.0" → "bun")
+fn discovered_package_manager(discovery: &DiscoveryResult) -> &str {
+    discovery
+        .package_manager
+        .as_deref()
+        .and_then(|pm| pm.split('@').next())
+        .filter(|pm| !pm.is_empty())
+        .unwrap_or(DEFAULT_PACKAGE_MANAGER)
+}
+
+/// Command that runs a package.json script.
+///
+/// `bun build`/`bun test` and `npm build` are built-in subcommands rather
+/// than script runners, so only pnpm and yarn get the short form.
+fn run_script(pm: &str, script: &str) -> String {
+    match pm {
+        "pnpm" | "yarn" => format!("{} {}", pm, script),
+        _ => format!("{} run {}", pm, script),
+    }
+}
+
 /// Format an IndexMap as a TOML inline table
 /// e.g., { "dev" = "next dev", "build" = "next build" }
 pub(super) fn format_inline_table(map: &IndexMap<String, String>) -> String {
@@ -149,12 +176,22 @@ pub(super) fn generate_manifest_content(discovery: &DiscoveryResult) -> Result<S
         lines.push("".to_string());
     }
 
-    // Commands section
+    // Commands section. The detected package manager is used here only: it
+    // is not written to [workspace].package_manager, which would also change
+    // what `airis gen` puts in package.json.
+    let pm = discovered_package_manager(discovery);
+    let run = format!(
+        "docker compose run --rm {}",
+        discovery
+            .compose_service
+            .as_deref()
+            .unwrap_or(DEFAULT_SERVICE)
+    );
     lines.push("[commands]".to_string());
-    lines.push("install = \"docker compose run --rm node pnpm install\"".to_string());
+    lines.push(format!("install = \"{} {} install\"", run, pm));
     lines.push("dev = \"docker compose up\"".to_string());
-    lines.push("build = \"docker compose run --rm node pnpm build\"".to_string());
-    lines.push("test = \"docker compose run --rm node pnpm test\"".to_string());
+    lines.push(format!("build = \"{} {}\"", run, run_script(pm, "build")));
+    lines.push(format!("test = \"{} {}\"", run, run_script(pm, "test")));
     lines.push("".to_string());
 
     // Versioning section
@@ -50,6 +50,8 @@ fn create_test_discovery() -> DiscoveryResult {
             m.insert("typescript".to_string(), "^5.0.0".to_string());
             m
         },
+        package_manager: None,
+        compose_service: None,
     }
 }
 
@@ -111,6 +113,35 @@ fn test_generate_manifest_content() {
     assert!(content.contains("deps = {"));
 }
 
+#[test]
+fn test_generate_manifest_content_defaults_to_pnpm_and_node_service() {
+    use super::manifest_gen::generate_manifest_content;
+
+    let content = generate_manifest_content(&create_test_discovery()).unwrap();
+
+    assert!(content.contains("install = \"docker compose run --rm node pnpm install\""));
+    assert!(content.contains("build = \"docker compose run --rm node pnpm build\""));
+    assert!(!content.contains("package_manager ="));
+}
+
+#[test]
+fn test_generate_manifest_content_uses_discovered_bun_and_service() {
+    use super::manifest_gen::generate_manifest_content;
+
+    let mut discovery = create_test_discovery();
+    discovery.package_manager = Some("bun@1.1.38".to_string());
+    discovery.compose_service = Some("workspace".to_string());
+    let content = generate_manifest_content(&discovery).unwrap();
+
+    // Used for commands only; [workspace] keeps the default package manager
+    assert!(!content.contains("package_manager ="));
+    assert!(content.contains("install = \"docker compose run --rm workspace bun install\""));
+    assert!(content.contains("build = \"docker compose run --rm workspace bun run build\""));
+    assert!(content.contains("test = \"docker compose run --rm workspace bun run test\""));
+    assert!(!content.contains("pnpm"));
+    toml::from_str::<toml::Value>(&content).unwrap();
+}
+
 #[test]
 fn test_dry_run_does_not_create_files() {
     let dir = tempdir().unwrap();
@@ -120,6 +151,8 @@ fn test_dry_run_does_not_create_files() {
         libs: vec![],
         compose_files: vec![],
         catalog: IndexMap::new(),
+        package_manager: None,
+        compose_service: None,
     };
 
     let migration_plan = plan(discovery).unwrap();
@@ -138,6 +171,8 @@ fn test_execute_creates_manifest() {
         libs: vec![],
         compose_files: vec![],
         catalog: IndexMap::new(),
+        package_manager: None,
+        compose_service: None,
     };
 
     let migration_plan = plan(discovery).unwrap();
@@ -7,7 +7,10 @@ pub mod diff;
 pub mod discover;
 pub mod docs;
 pub mod doctor;
+pub mod extension;
 pub mod generate;
+pub mod generate_deps_config;
+pub mod generate_openapi;
 pub mod generate_types;
 pub mod manifest_cmd;
 pub mod mcp;
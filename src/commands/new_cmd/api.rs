@@ -2,11 +2,18 @@
 
 use anyhow::{Context, Result};
 use colored::Colorize;
+use indexmap::IndexMap;
 use std::fs;
 use std::path::Path;
 
+use crate::channel::mirror_image;
+
 /// Generate a Hono API project
-pub fn generate_api_project(project_dir: &Path, name: &str) -> Result<()> {
+pub fn generate_api_project(
+    project_dir: &Path,
+    name: &str,
+    images: &IndexMap<String, String>,
+) -> Result<()> {
     fs::create_dir_all(project_dir.join("src/routes"))
         .context("Failed to create src/routes directory")?;
 
@@ -21,7 +28,7 @@ pub fn generate_api_project(project_dir: &Path, name: &str) -> Result<()> {
     "dev": "tsx watch src/index.ts",
     "build": "tsup src/index.ts --format esm --dts",
     "start": "node dist/index.js",
-    "test": "vitest",
+    "test": "vitest run",
     "lint": "biome check src/"
   }},
   "dependencies": {{
@@ -107,8 +114,25 @@ health.get('/', (c) => {
 "#;
     fs::write(project_dir.join("src/routes/health.ts"), health_ts)?;
 
+    // src/routes/health.test.ts — keeps `pnpm test` green out of the box
+    let health_test_ts = r#"import { describe, expect, it } from 'vitest'
+import { health } from './health'
+
+describe('GET /health', () => {
+  it('reports ok', async () => {
+    const res = await health.request('/')
+    expect(res.status).toBe(200)
+    expect(await res.json()).toMatchObject({ status: 'ok' })
+  })
+})
+"#;
+    fs::write(
+        project_dir.join("src/routes/health.test.ts"),
+        health_test_ts,
+    )?;
+
     // Dockerfile — pnpm installed without version pin (scaffold = fresh project)
-    let node_image = crate::channel::defaults::NODE_LTS_IMAGE;
+    let node_image = mirror_image(crate::channel::defaults::NODE_LTS_IMAGE, "node", images);
     let dockerfile = format!(
         r#"FROM {node_image} AS builder
 WORKDIR /app
@@ -173,6 +197,7 @@ pnpm test
     println!("  {} tsconfig.json", "✓".green());
     println!("  {} src/index.ts", "✓".green());
     println!("  {} src/routes/health.ts", "✓".green());
+    println!("  {} src/routes/health.test.ts", "✓".green());
     println!("  {} Dockerfile", "✓".green());
     println!("  {} .gitignore", "✓".green());
     println!("  {} README.md", "✓".green());
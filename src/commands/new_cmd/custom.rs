@@ -0,0 +1,77 @@
+//! User-provided project templates (`[templates] dir` / `AIRIS_TEMPLATES_DIR`)
+
+use anyhow::{Context, Result, bail};
+use indexmap::IndexMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::manifest::Manifest;
+use crate::templates::TemplateEngine;
+
+/// Environment variable that overrides `[templates] dir`
+pub(super) const TEMPLATES_DIR_ENV: &str = "AIRIS_TEMPLATES_DIR";
+
+/// Locate the override template set for `<category>-<runtime>`, if any.
+pub(super) fn find_template_dir(
+    manifest: Option<&Manifest>,
+    category: &str,
+    runtime: &str,
+) -> Option<PathBuf> {
+    let root = std::env::var(TEMPLATES_DIR_ENV)
+        .ok()
+        .filter(|s| !s.is_empty())
+        .or_else(|| manifest.and_then(|m| m.templates.dir.clone()))?;
+
+    let dir = Path::new(&root).join(format!("{}-{}", category, runtime));
+    dir.is_dir().then_some(dir)
+}
+
+/// Copy a template set into `project_dir`, rendering every text file (and
+/// path) through `TemplateEngine`. A trailing `.hbs` extension is stripped.
+pub(super) fn generate_from_template_dir(
+    template_dir: &Path,
+    project_dir: &Path,
+    vars: &IndexMap<String, String>,
+) -> Result<()> {
+    let engine = TemplateEngine::new()?;
+
+    for entry in WalkDir::new(template_dir).min_depth(1) {
+        let entry = entry?;
+        let rel = entry
+            .path()
+            .strip_prefix(template_dir)
+            .expect("walkdir yields paths under its root");
+
+        let rel = engine.render_str(&rel.to_string_lossy(), vars);
+        let rel = Path::new(rel.strip_suffix(".hbs").unwrap_or(&rel));
+        // A rendered `{{name}}` must not escape the new project
+        if !rel.components().all(|c| matches!(c, Component::Normal(_))) {
+            bail!(
+                "Template path {} renders to {}, which is outside the project",
+                entry.path().display(),
+                rel.display()
+            );
+        }
+        let dest = project_dir.join(rel);
+
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&dest)?;
+            continue;
+        }
+
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let bytes = fs::read(entry.path())
+            .with_context(|| format!("Failed to read template {}", entry.path().display()))?;
+        match String::from_utf8(bytes) {
+            Ok(text) => fs::write(&dest, engine.render_str(&text, vars))?,
+            // Binary assets (images, fonts) are copied verbatim
+            Err(e) => fs::write(&dest, e.into_bytes())?,
+        }
+    }
+
+    Ok(())
+}
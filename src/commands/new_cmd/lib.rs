@@ -27,7 +27,7 @@ pub fn generate_lib_project(project_dir: &Path, name: &str) -> Result<()> {
   "scripts": {{
     "build": "tsup src/index.ts --format esm --dts",
     "dev": "tsup src/index.ts --format esm --dts --watch",
-    "test": "vitest",
+    "test": "vitest run",
     "lint": "biome check src/"
   }},
   "devDependencies": {{
@@ -73,6 +73,18 @@ export default {{ hello }}
     );
     fs::write(project_dir.join("src/index.ts"), index_ts)?;
 
+    // src/index.test.ts — keeps `pnpm test` green out of the box
+    let index_test_ts = r#"import { describe, expect, it } from 'vitest'
+import { hello } from './index'
+
+describe('hello', () => {
+  it('greets by name', () => {
+    expect(hello('airis')).toBe('Hello, airis!')
+  })
+})
+"#;
+    fs::write(project_dir.join("src/index.test.ts"), index_test_ts)?;
+
     // .gitignore
     let gitignore = r#"node_modules/
 dist/
@@ -83,6 +95,7 @@ dist/
     println!("  {} package.json", "✓".green());
     println!("  {} tsconfig.json", "✓".green());
     println!("  {} src/index.ts", "✓".green());
+    println!("  {} src/index.test.ts", "✓".green());
     println!("  {} .gitignore", "✓".green());
 
     Ok(())
@@ -1,9 +1,11 @@
 //! New command: scaffold new apps, services, and libraries from templates
 
 mod api;
+mod custom;
 mod edge;
 mod lib;
 mod python;
+mod register;
 mod rust;
 mod supabase;
 mod web;
@@ -13,18 +15,21 @@ mod tests;
 
 use anyhow::{Context, Result, bail};
 use colored::Colorize;
+use indexmap::IndexMap;
 use std::fs;
 use std::path::Path;
 
 use crate::manifest::{MANIFEST_FILE, Manifest};
 
 use api::generate_api_project;
+use custom::{find_template_dir, generate_from_template_dir};
 use edge::generate_edge_function;
 use lib::generate_lib_project;
 use python::{generate_py_api, generate_py_lib};
+use register::{register_in_manifest, registered_framework};
 use rust::generate_rust_service;
 use supabase::{generate_supabase_realtime, generate_supabase_trigger};
-use web::generate_web_project;
+use web::{generate_remix_project, generate_sveltekit_project, generate_web_project};
 
 /// Get the base directory for a template category
 fn get_base_dir(category: &str) -> &str {
@@ -47,7 +52,10 @@ fn resolve_runtime(manifest: &Manifest, runtime: &str) -> String {
 }
 
 /// Run the new command with runtime selection
-pub fn run_with_runtime(category: &str, name: &str, runtime: &str) -> Result<()> {
+///
+/// When `register` is set and a manifest.toml exists, the new project is
+/// appended to it as an `[[app]]` entry (skipped if already declared).
+pub fn run_with_runtime(category: &str, name: &str, runtime: &str, register: bool) -> Result<()> {
     // Validate name
     if name.is_empty() {
         bail!("Project name cannot be empty");
@@ -74,6 +82,12 @@ pub fn run_with_runtime(category: &str, name: &str, runtime: &str) -> Result<()>
         runtime.to_string()
     };
 
+    // Base image mirrors for generated Dockerfiles
+    let images = manifest
+        .as_ref()
+        .map(|m| m.build.images.clone())
+        .unwrap_or_default();
+
     let base_dir = get_base_dir(category);
     let project_dir = Path::new(base_dir).join(name);
 
@@ -99,12 +113,26 @@ pub fn run_with_runtime(category: &str, name: &str, runtime: &str) -> Result<()>
         project_dir.display().to_string().cyan()
     );
 
-    // Generate project based on category and runtime
+    // Prefer an override template set when one exists for this pair
+    let template_dir = find_template_dir(manifest.as_ref(), category, &resolved_runtime);
+    if let Some(ref dir) = template_dir {
+        println!("  Using template {}", dir.display().to_string().dimmed());
+        let mut vars = IndexMap::new();
+        vars.insert("name".to_string(), name.to_string());
+        vars.insert("category".to_string(), category.to_string());
+        vars.insert("runtime".to_string(), resolved_runtime.clone());
+        generate_from_template_dir(dir, &project_dir, &vars)?;
+    }
+
+    // Otherwise generate project from the built-in templates
     match (category, resolved_runtime.as_str()) {
-        ("api", "hono") => generate_api_project(&project_dir, name)?,
-        ("api", "fastapi") => generate_py_api(&project_dir, name)?,
-        ("api", "rust-axum") => generate_rust_service(&project_dir, name)?,
+        _ if template_dir.is_some() => {}
+        ("api", "hono") => generate_api_project(&project_dir, name, &images)?,
+        ("api", "fastapi") => generate_py_api(&project_dir, name, &images)?,
+        ("api", "rust-axum") => generate_rust_service(&project_dir, name, &images)?,
         ("web", "nextjs") => generate_web_project(&project_dir, name)?,
+        ("web", "sveltekit") => generate_sveltekit_project(&project_dir, name)?,
+        ("web", "remix") => generate_remix_project(&project_dir, name)?,
         ("lib", "ts") => generate_lib_project(&project_dir, name)?,
         ("lib", "python") => generate_py_lib(&project_dir, name)?,
         ("edge", "deno") => generate_edge_function(&project_dir, name)?,
@@ -114,7 +142,7 @@ pub fn run_with_runtime(category: &str, name: &str, runtime: &str) -> Result<()>
             bail!(
                 "Unknown runtime '{}' for category '{}'. Available runtimes:\n  \
                 api: hono, fastapi, rust-axum\n  \
-                web: nextjs\n  \
+                web: nextjs, sveltekit, remix\n  \
                 lib: ts, python\n  \
                 edge: deno\n  \
                 supabase-trigger: plpgsql\n  \
@@ -125,6 +153,29 @@ pub fn run_with_runtime(category: &str, name: &str, runtime: &str) -> Result<()>
         }
     }
 
+    if register
+        && manifest.is_some()
+        && let Some(framework) = registered_framework(category, &resolved_runtime)
+    {
+        let kind = if category == "lib" { "lib" } else { "app" };
+        let path = format!("{}/{}", base_dir, name);
+        if register_in_manifest(name, &path, kind, framework)? {
+            println!(
+                "  {} registered [[app]] {} in {}",
+                "✓".green(),
+                name.cyan(),
+                MANIFEST_FILE
+            );
+        } else {
+            println!(
+                "  {} {} already declared in {}",
+                "⏭️".dimmed(),
+                name,
+                MANIFEST_FILE
+            );
+        }
+    }
+
     println!();
     println!("{}", "✅ Project created successfully!".green());
     println!();
@@ -2,9 +2,12 @@
 
 use anyhow::{Context, Result};
 use colored::Colorize;
+use indexmap::IndexMap;
 use std::fs;
 use std::path::Path;
 
+use crate::channel::mirror_image;
+
 /// Generate a Python library (uv + hatchling, src layout)
 pub fn generate_py_lib(project_dir: &Path, name: &str) -> Result<()> {
     let pkg_name = name.replace('-', "_");
@@ -60,6 +63,22 @@ testpaths = ["tests"]
         init_py,
     )?;
 
+    // tests/test_<pkg>.py — keeps `pytest` green out of the box
+    fs::create_dir_all(project_dir.join("tests")).context("Failed to create tests directory")?;
+    let test_py = format!(
+        r#"import {}
+
+
+def test_import():
+    assert {}.__doc__ == "{}"
+"#,
+        pkg_name, pkg_name, name
+    );
+    fs::write(
+        project_dir.join(format!("tests/test_{}.py", pkg_name)),
+        test_py,
+    )?;
+
     // .gitignore
     let gitignore = r#"__pycache__/
 *.py[cod]
@@ -72,13 +91,18 @@ dist/
 
     println!("  {} pyproject.toml", "✓".green(),);
     println!("  {} src/{}/__init__.py", "✓".green(), pkg_name);
+    println!("  {} tests/test_{}.py", "✓".green(), pkg_name);
     println!("  {} .gitignore", "✓".green());
 
     Ok(())
 }
 
 /// Generate a Python FastAPI service
-pub fn generate_py_api(project_dir: &Path, name: &str) -> Result<()> {
+pub fn generate_py_api(
+    project_dir: &Path,
+    name: &str,
+    images: &IndexMap<String, String>,
+) -> Result<()> {
     fs::create_dir_all(project_dir.join("app")).context("Failed to create app directory")?;
 
     // pyproject.toml
@@ -104,6 +128,9 @@ dev = [
 [build-system]
 requires = ["hatchling"]
 build-backend = "hatchling.build"
+
+[tool.pytest.ini_options]
+testpaths = ["tests"]
 "#,
         name
     );
@@ -136,8 +163,24 @@ async def health():
     // app/__init__.py
     fs::write(project_dir.join("app/__init__.py"), "")?;
 
+    // tests/test_main.py — keeps `pytest` green out of the box
+    fs::create_dir_all(project_dir.join("tests")).context("Failed to create tests directory")?;
+    let test_main_py = r#"from fastapi.testclient import TestClient
+
+from app.main import app
+
+client = TestClient(app)
+
+
+def test_health():
+    response = client.get("/health")
+    assert response.status_code == 200
+    assert response.json()["status"] == "ok"
+"#;
+    fs::write(project_dir.join("tests/test_main.py"), test_main_py)?;
+
     // Dockerfile
-    let python_image = crate::channel::defaults::PYTHON_IMAGE;
+    let python_image = mirror_image(crate::channel::defaults::PYTHON_IMAGE, "python", images);
     let dockerfile = format!(
         r#"FROM {python_image}
 
@@ -171,6 +214,7 @@ dist/
     println!("  {} pyproject.toml", "✓".green());
     println!("  {} app/main.py", "✓".green());
     println!("  {} app/__init__.py", "✓".green());
+    println!("  {} tests/test_main.py", "✓".green());
     println!("  {} Dockerfile", "✓".green());
     println!("  {} .gitignore", "✓".green());
 
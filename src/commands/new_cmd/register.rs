@@ -0,0 +1,111 @@
+//! Register scaffolded projects in manifest.toml
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+use crate::manifest::{MANIFEST_FILE, update_toml_text};
+use crate::safe_fs::SafeFS;
+
+/// Framework recorded in `[[app]]` for a scaffolded (category, runtime) pair.
+///
+/// Returns `None` for templates that don't live in a workspace package
+/// directory (edge functions, Supabase triggers/realtime).
+pub(super) fn registered_framework(category: &str, runtime: &str) -> Option<&'static str> {
+    match (category, runtime) {
+        ("api", "hono") => Some("hono"),
+        ("api", "fastapi") => Some("python"),
+        ("api", "rust-axum") => Some("rust"),
+        ("web", "nextjs") => Some("nextjs"),
+        ("web", "sveltekit") => Some("sveltekit"),
+        ("web", "remix") => Some("remix"),
+        ("lib", "ts") => Some("node"),
+        ("lib", "python") => Some("python"),
+        _ => None,
+    }
+}
+
+/// Whether manifest.toml already declares a project with this name or path,
+/// either as an `[[app]]` entry or an `[apps.<name>]` / `[libs.<name>]` table.
+pub(super) fn is_registered(content: &str, name: &str, path: &str) -> Result<bool> {
+    let value: toml::Value = toml::from_str(content).context("Failed to parse manifest.toml")?;
+
+    let in_app_array = value
+        .get("app")
+        .and_then(|v| v.as_array())
+        .is_some_and(|apps| {
+            apps.iter().any(|app| {
+                app.get("name").and_then(|v| v.as_str()) == Some(name)
+                    || app.get("path").and_then(|v| v.as_str()) == Some(path)
+            })
+        });
+
+    let in_table = |section: &str| {
+        value
+            .get(section)
+            .and_then(|v| v.as_table())
+            .is_some_and(|t| t.contains_key(name))
+    };
+
+    Ok(in_app_array || in_table("apps") || in_table("libs"))
+}
+
+/// Return `content` with an `[[app]]` entry appended.
+///
+/// Goes through the format-preserving manifest editor, so values are escaped
+/// and existing comments and formatting survive.
+pub(super) fn append_app_entry(
+    content: &str,
+    name: &str,
+    path: &str,
+    kind: &str,
+    framework: &str,
+) -> Result<String> {
+    let before: toml::Value = toml::from_str(content).context("Failed to parse manifest.toml")?;
+
+    let mut entry = toml::Table::new();
+    entry.insert("name".into(), name.into());
+    entry.insert("path".into(), path.into());
+    entry.insert("kind".into(), kind.into());
+    entry.insert("framework".into(), framework.into());
+
+    let mut after = before.clone();
+    let root = after
+        .as_table_mut()
+        .context("manifest.toml is not a table")?;
+    root.entry("app")
+        .or_insert_with(|| toml::Value::Array(Vec::new()))
+        .as_array_mut()
+        .context("`app` in manifest.toml is not an array of tables")?
+        .push(entry.into());
+
+    update_toml_text(content, &before, &after)
+}
+
+/// Append an `[[app]]` entry for a freshly scaffolded project.
+///
+/// The entry is added in place (rather than re-serializing the manifest) so
+/// comments and formatting survive, and written through `SafeFS` so the
+/// previous manifest.toml is backed up. Returns `false` when the project is
+/// already declared.
+pub(super) fn register_in_manifest(
+    name: &str,
+    path: &str,
+    kind: &str,
+    framework: &str,
+) -> Result<bool> {
+    let content = fs::read_to_string(MANIFEST_FILE)
+        .with_context(|| format!("Failed to read {}", MANIFEST_FILE))?;
+
+    if is_registered(&content, name, path)? {
+        return Ok(false);
+    }
+
+    let updated = append_app_entry(&content, name, path, kind, framework)
+        .context("Refusing to register project")?;
+
+    let safe_fs = SafeFS::current(false)?;
+    safe_fs.edit(Path::new(MANIFEST_FILE), updated)?;
+
+    Ok(true)
+}
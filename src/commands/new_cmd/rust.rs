@@ -2,11 +2,18 @@
 
 use anyhow::{Context, Result};
 use colored::Colorize;
+use indexmap::IndexMap;
 use std::fs;
 use std::path::Path;
 
+use crate::channel::mirror_image;
+
 /// Generate a Rust service
-pub fn generate_rust_service(project_dir: &Path, name: &str) -> Result<()> {
+pub fn generate_rust_service(
+    project_dir: &Path,
+    name: &str,
+    images: &IndexMap<String, String>,
+) -> Result<()> {
     fs::create_dir_all(project_dir.join("src")).context("Failed to create src directory")?;
 
     // Cargo.toml
@@ -67,13 +74,24 @@ async fn main() -> anyhow::Result<()> {{
 
     Ok(())
 }}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[tokio::test]
+    async fn health_reports_ok() {{
+        let Json(body) = health().await;
+        assert_eq!(body.status, "ok");
+    }}
+}}
 "#,
         name, name
     );
     fs::write(project_dir.join("src/main.rs"), main_rs)?;
 
     // Dockerfile
-    let rust_image = crate::channel::defaults::RUST_IMAGE;
+    let rust_image = mirror_image(crate::channel::defaults::RUST_IMAGE, "rust", images);
     let alpine_image = crate::channel::defaults::ALPINE_IMAGE;
     let bin_name = name.replace('-', "_");
     let dockerfile = format!(
@@ -14,14 +14,14 @@ fn test_get_base_dir() {
 
 #[test]
 fn test_empty_name_rejected() {
-    let result = run_with_runtime("api", "", "hono");
+    let result = run_with_runtime("api", "", "hono", false);
     assert!(result.is_err());
     assert!(result.unwrap_err().to_string().contains("cannot be empty"));
 }
 
 #[test]
 fn test_invalid_name_rejected() {
-    let result = run_with_runtime("api", "my app", "hono");
+    let result = run_with_runtime("api", "my app", "hono", false);
     assert!(result.is_err());
     assert!(result.unwrap_err().to_string().contains("alphanumeric"));
 }
@@ -31,13 +31,30 @@ fn test_generate_api_project() {
     let temp_dir = TempDir::new().unwrap();
     let project_dir = temp_dir.path().join("test-api");
 
-    api::generate_api_project(&project_dir, "test-api").unwrap();
+    api::generate_api_project(&project_dir, "test-api", &IndexMap::new()).unwrap();
 
     assert!(project_dir.join("package.json").exists());
     assert!(project_dir.join("tsconfig.json").exists());
     assert!(project_dir.join("src/index.ts").exists());
     assert!(project_dir.join("src/routes/health.ts").exists());
+    assert!(project_dir.join("src/routes/health.test.ts").exists());
     assert!(project_dir.join("Dockerfile").exists());
+
+    let package_json = fs::read_to_string(project_dir.join("package.json")).unwrap();
+    assert!(package_json.contains(r#""test": "vitest run""#));
+}
+
+#[test]
+fn test_generate_api_project_uses_image_mirror() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("test-api");
+    let mut images = IndexMap::new();
+    images.insert("node".to_string(), "registry.corp/mirror/node".to_string());
+
+    api::generate_api_project(&project_dir, "test-api", &images).unwrap();
+
+    let dockerfile = fs::read_to_string(project_dir.join("Dockerfile")).unwrap();
+    assert!(dockerfile.starts_with("FROM registry.corp/mirror/node:24-alpine AS builder"));
 }
 
 #[test]
@@ -50,6 +67,7 @@ fn test_generate_lib_project() {
     assert!(project_dir.join("package.json").exists());
     assert!(project_dir.join("tsconfig.json").exists());
     assert!(project_dir.join("src/index.ts").exists());
+    assert!(project_dir.join("src/index.test.ts").exists());
 }
 
 #[test]
@@ -57,11 +75,14 @@ fn test_generate_rust_service() {
     let temp_dir = TempDir::new().unwrap();
     let project_dir = temp_dir.path().join("test-rust");
 
-    rust::generate_rust_service(&project_dir, "test-rust").unwrap();
+    rust::generate_rust_service(&project_dir, "test-rust", &IndexMap::new()).unwrap();
 
     assert!(project_dir.join("Cargo.toml").exists());
     assert!(project_dir.join("src/main.rs").exists());
     assert!(project_dir.join("Dockerfile").exists());
+
+    let main_rs = fs::read_to_string(project_dir.join("src/main.rs")).unwrap();
+    assert!(main_rs.contains("#[tokio::test]"));
 }
 
 #[test]
@@ -73,6 +94,7 @@ fn test_generate_py_lib() {
 
     assert!(project_dir.join("pyproject.toml").exists());
     assert!(project_dir.join("src/test_lib/__init__.py").exists());
+    assert!(project_dir.join("tests/test_test_lib.py").exists());
     assert!(project_dir.join(".gitignore").exists());
 }
 
@@ -81,9 +103,179 @@ fn test_generate_py_api() {
     let temp_dir = TempDir::new().unwrap();
     let project_dir = temp_dir.path().join("test-py");
 
-    python::generate_py_api(&project_dir, "test-py").unwrap();
+    python::generate_py_api(&project_dir, "test-py", &IndexMap::new()).unwrap();
 
     assert!(project_dir.join("pyproject.toml").exists());
     assert!(project_dir.join("app/main.py").exists());
+    assert!(project_dir.join("tests/test_main.py").exists());
     assert!(project_dir.join("Dockerfile").exists());
 }
+
+#[test]
+fn test_generate_sveltekit_project() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("test-svelte");
+
+    web::generate_sveltekit_project(&project_dir, "test-svelte").unwrap();
+
+    assert!(project_dir.join("package.json").exists());
+    assert!(project_dir.join("svelte.config.js").exists());
+    assert!(project_dir.join("vite.config.ts").exists());
+    assert!(project_dir.join("src/routes/+page.svelte").exists());
+    assert!(project_dir.join(".gitignore").exists());
+
+    let svelte_config = fs::read_to_string(project_dir.join("svelte.config.js")).unwrap();
+    assert!(svelte_config.contains("@sveltejs/adapter-node"));
+
+    let package_json = fs::read_to_string(project_dir.join("package.json")).unwrap();
+    assert!(package_json.contains(r#""@sveltejs/kit": "catalog:""#));
+}
+
+#[test]
+fn test_generate_remix_project() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("test-remix");
+
+    web::generate_remix_project(&project_dir, "test-remix").unwrap();
+
+    assert!(project_dir.join("package.json").exists());
+    assert!(project_dir.join("vite.config.ts").exists());
+    assert!(project_dir.join("app/root.tsx").exists());
+    assert!(project_dir.join("app/routes/_index.tsx").exists());
+    assert!(project_dir.join(".gitignore").exists());
+
+    let package_json = fs::read_to_string(project_dir.join("package.json")).unwrap();
+    assert!(package_json.contains("remix-serve ./build/server/index.js"));
+    assert!(package_json.contains(r#""@remix-run/react": "catalog:""#));
+}
+
+#[test]
+fn test_registered_framework() {
+    assert_eq!(register::registered_framework("api", "hono"), Some("hono"));
+    assert_eq!(
+        register::registered_framework("web", "nextjs"),
+        Some("nextjs")
+    );
+    assert_eq!(
+        register::registered_framework("lib", "python"),
+        Some("python")
+    );
+    assert_eq!(
+        register::registered_framework("web", "sveltekit"),
+        Some("sveltekit")
+    );
+    assert_eq!(
+        register::registered_framework("web", "remix"),
+        Some("remix")
+    );
+    assert_eq!(register::registered_framework("edge", "deno"), None);
+}
+
+#[test]
+fn test_is_registered_detects_existing_entries() {
+    let manifest = r#"
+[[app]]
+name = "web"
+path = "apps/web"
+
+[libs.ui]
+path = "libs/ui"
+"#;
+    assert!(register::is_registered(manifest, "web", "apps/other").unwrap());
+    assert!(register::is_registered(manifest, "renamed", "apps/web").unwrap());
+    assert!(register::is_registered(manifest, "ui", "libs/ui").unwrap());
+    assert!(!register::is_registered(manifest, "api", "apps/api").unwrap());
+}
+
+#[test]
+fn test_append_app_entry_escapes_and_keeps_comments() {
+    let manifest = "# keep me\n[project]\nid = \"demo\"\n\n[[app]]\nname = \"web\" # main site\n";
+    let updated =
+        register::append_app_entry(manifest, "api \"v2\"", "apps\\api", "app", "hono").unwrap();
+
+    let value: toml::Value = toml::from_str(&updated).unwrap();
+    let apps = value["app"].as_array().unwrap();
+    assert_eq!(apps.len(), 2);
+    assert_eq!(apps[1]["name"].as_str(), Some("api \"v2\""));
+    assert_eq!(apps[1]["path"].as_str(), Some("apps\\api"));
+    assert_eq!(apps[1]["framework"].as_str(), Some("hono"));
+    assert!(updated.starts_with("# keep me"));
+    assert!(updated.contains("name = \"web\" # main site\n"));
+
+    // First entry in a manifest without any [[app]]
+    let updated = register::append_app_entry(
+        "[project]\nid = \"demo\"\n",
+        "api",
+        "apps/api",
+        "app",
+        "hono",
+    )
+    .unwrap();
+    assert!(updated.contains("[[app]]\nname = \"api\"\npath = \"apps/api\"\n"));
+}
+
+#[test]
+fn test_find_template_dir_from_manifest() {
+    let templates = TempDir::new().unwrap();
+    fs::create_dir_all(templates.path().join("api-hono")).unwrap();
+
+    let mut manifest = Manifest::default_with_project("demo");
+    manifest.templates.dir = Some(templates.path().to_string_lossy().into_owned());
+
+    assert_eq!(
+        custom::find_template_dir(Some(&manifest), "api", "hono"),
+        Some(templates.path().join("api-hono"))
+    );
+    // No override for this pair: fall back to built-ins
+    assert_eq!(
+        custom::find_template_dir(Some(&manifest), "web", "nextjs"),
+        None
+    );
+}
+
+#[test]
+fn test_generate_from_template_dir_renders_vars() {
+    let templates = TempDir::new().unwrap();
+    let src = templates.path().join("api-hono");
+    fs::create_dir_all(src.join("src")).unwrap();
+    fs::write(src.join("package.json.hbs"), r#"{"name": "@org/{{name}}"}"#).unwrap();
+    fs::write(src.join("src/{{name}}.ts"), "// {{name}} ({{runtime}})\n").unwrap();
+    fs::write(src.join(".eslintrc.json"), "{}").unwrap();
+
+    let out = TempDir::new().unwrap();
+    let project_dir = out.path().join("billing");
+    let mut vars = IndexMap::new();
+    vars.insert("name".to_string(), "billing".to_string());
+    vars.insert("runtime".to_string(), "hono".to_string());
+
+    custom::generate_from_template_dir(&src, &project_dir, &vars).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(project_dir.join("package.json")).unwrap(),
+        r#"{"name": "@org/billing"}"#
+    );
+    assert_eq!(
+        fs::read_to_string(project_dir.join("src/billing.ts")).unwrap(),
+        "// billing (hono)\n"
+    );
+    assert!(project_dir.join(".eslintrc.json").exists());
+}
+
+#[test]
+fn test_generate_from_template_dir_rejects_escaping_paths() {
+    let templates = TempDir::new().unwrap();
+    let src = templates.path().join("api-hono");
+    fs::create_dir_all(&src).unwrap();
+    fs::write(src.join("{{name}}.ts"), "x").unwrap();
+
+    let out = TempDir::new().unwrap();
+    let project_dir = out.path().join("billing");
+    for name in ["../escaped", "/tmp/escaped", "a/../../escaped"] {
+        let mut vars = IndexMap::new();
+        vars.insert("name".to_string(), name.to_string());
+
+        let err = custom::generate_from_template_dir(&src, &project_dir, &vars).unwrap_err();
+        assert!(err.to_string().contains("outside the project"), "{}", name);
+    }
+    assert!(!out.path().join("escaped.ts").exists());
+}
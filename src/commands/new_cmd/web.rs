@@ -1,4 +1,4 @@
-//! Next.js web project scaffolding
+//! Web project scaffolding (Next.js, SvelteKit, Remix)
 
 use anyhow::{Context, Result};
 use colored::Colorize;
@@ -126,3 +126,266 @@ out/
 
     Ok(())
 }
+
+/// Generate a SvelteKit web project
+///
+/// Uses `@sveltejs/adapter-node`, which emits a self-contained Node server
+/// into `build/` (run with `node build`).
+pub fn generate_sveltekit_project(project_dir: &Path, name: &str) -> Result<()> {
+    fs::create_dir_all(project_dir.join("src/routes"))
+        .context("Failed to create src/routes directory")?;
+
+    // package.json
+    let package_json = format!(
+        r#"{{
+  "name": "{}",
+  "version": "0.1.0",
+  "private": true,
+  "type": "module",
+  "scripts": {{
+    "dev": "vite dev",
+    "build": "vite build",
+    "start": "node build",
+    "preview": "vite preview",
+    "check": "svelte-kit sync && svelte-check --tsconfig ./tsconfig.json"
+  }},
+  "devDependencies": {{
+    "@sveltejs/adapter-node": "catalog:",
+    "@sveltejs/kit": "catalog:",
+    "@sveltejs/vite-plugin-svelte": "catalog:",
+    "svelte": "catalog:",
+    "svelte-check": "catalog:",
+    "typescript": "catalog:",
+    "vite": "catalog:"
+  }}
+}}
+"#,
+        name
+    );
+    fs::write(project_dir.join("package.json"), package_json)?;
+
+    // svelte.config.js — adapter-node writes a standalone server to build/
+    let svelte_config = r#"import adapter from '@sveltejs/adapter-node'
+import { vitePreprocess } from '@sveltejs/vite-plugin-svelte'
+
+/** @type {import('@sveltejs/kit').Config} */
+const config = {
+  preprocess: vitePreprocess(),
+  kit: {
+    adapter: adapter({ out: 'build' }),
+  },
+}
+
+export default config
+"#;
+    fs::write(project_dir.join("svelte.config.js"), svelte_config)?;
+
+    // vite.config.ts
+    let vite_config = r#"import { sveltekit } from '@sveltejs/kit/vite'
+import { defineConfig } from 'vite'
+
+export default defineConfig({
+  plugins: [sveltekit()],
+})
+"#;
+    fs::write(project_dir.join("vite.config.ts"), vite_config)?;
+
+    // tsconfig.json — SvelteKit generates its own base in .svelte-kit/
+    let tsconfig = r#"{
+  "extends": "./.svelte-kit/tsconfig.json",
+  "compilerOptions": {
+    "allowJs": true,
+    "checkJs": true,
+    "esModuleInterop": true,
+    "forceConsistentCasingInFileNames": true,
+    "resolveJsonModule": true,
+    "skipLibCheck": true,
+    "sourceMap": true,
+    "strict": true,
+    "moduleResolution": "bundler"
+  }
+}
+"#;
+    fs::write(project_dir.join("tsconfig.json"), tsconfig)?;
+
+    // src/app.html
+    let app_html = r#"<!doctype html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+    %sveltekit.head%
+  </head>
+  <body data-sveltekit-preload-data="hover">
+    <div style="display: contents">%sveltekit.body%</div>
+  </body>
+</html>
+"#;
+    fs::write(project_dir.join("src/app.html"), app_html)?;
+
+    // src/routes/+page.svelte
+    let page = format!(
+        r#"<main style="padding: 2rem">
+  <h1>{}</h1>
+  <p>Welcome to your new SvelteKit app!</p>
+</main>
+"#,
+        name
+    );
+    fs::write(project_dir.join("src/routes/+page.svelte"), page)?;
+
+    // .gitignore
+    let gitignore = r#"node_modules/
+.svelte-kit/
+build/
+*.log
+.env
+.env.local
+"#;
+    fs::write(project_dir.join(".gitignore"), gitignore)?;
+
+    println!("  {} package.json", "✓".green());
+    println!("  {} svelte.config.js", "✓".green());
+    println!("  {} vite.config.ts", "✓".green());
+    println!("  {} tsconfig.json", "✓".green());
+    println!("  {} src/app.html", "✓".green());
+    println!("  {} src/routes/+page.svelte", "✓".green());
+    println!("  {} .gitignore", "✓".green());
+
+    Ok(())
+}
+
+/// Generate a Remix web project
+///
+/// Uses the Remix Vite plugin, which emits `build/server` and `build/client`.
+/// The output is not standalone: `remix-serve` and `node_modules` are needed
+/// at runtime.
+pub fn generate_remix_project(project_dir: &Path, name: &str) -> Result<()> {
+    fs::create_dir_all(project_dir.join("app/routes"))
+        .context("Failed to create app/routes directory")?;
+
+    // package.json
+    let package_json = format!(
+        r#"{{
+  "name": "{}",
+  "version": "0.1.0",
+  "private": true,
+  "type": "module",
+  "scripts": {{
+    "dev": "remix vite:dev",
+    "build": "remix vite:build",
+    "start": "remix-serve ./build/server/index.js",
+    "typecheck": "tsc"
+  }},
+  "dependencies": {{
+    "@remix-run/node": "catalog:",
+    "@remix-run/react": "catalog:",
+    "@remix-run/serve": "catalog:",
+    "isbot": "catalog:",
+    "react": "catalog:",
+    "react-dom": "catalog:"
+  }},
+  "devDependencies": {{
+    "@remix-run/dev": "catalog:",
+    "@types/react": "catalog:",
+    "@types/react-dom": "catalog:",
+    "typescript": "catalog:",
+    "vite": "catalog:",
+    "vite-tsconfig-paths": "catalog:"
+  }}
+}}
+"#,
+        name
+    );
+    fs::write(project_dir.join("package.json"), package_json)?;
+
+    // vite.config.ts — build output goes to build/server + build/client
+    let vite_config = r#"import { vitePlugin as remix } from '@remix-run/dev'
+import { defineConfig } from 'vite'
+import tsconfigPaths from 'vite-tsconfig-paths'
+
+export default defineConfig({
+  plugins: [remix({ buildDirectory: 'build' }), tsconfigPaths()],
+})
+"#;
+    fs::write(project_dir.join("vite.config.ts"), vite_config)?;
+
+    // tsconfig.json — extends base, Remix-specific options
+    let tsconfig = r#"{
+  "extends": "../../tsconfig.base.json",
+  "compilerOptions": {
+    "lib": ["DOM", "DOM.Iterable", "ES2022"],
+    "types": ["@remix-run/node", "vite/client"],
+    "jsx": "react-jsx",
+    "module": "ESNext",
+    "moduleResolution": "Bundler",
+    "noEmit": true,
+    "paths": { "~/*": ["./app/*"] }
+  },
+  "include": ["**/*.ts", "**/*.tsx"],
+  "exclude": ["node_modules", "build"]
+}
+"#;
+    fs::write(project_dir.join("tsconfig.json"), tsconfig)?;
+
+    // app/root.tsx
+    let root = r#"import { Links, Meta, Outlet, Scripts, ScrollRestoration } from '@remix-run/react'
+
+export default function App() {
+  return (
+    <html lang="en">
+      <head>
+        <meta charSet="utf-8" />
+        <meta name="viewport" content="width=device-width, initial-scale=1" />
+        <Meta />
+        <Links />
+      </head>
+      <body>
+        <Outlet />
+        <ScrollRestoration />
+        <Scripts />
+      </body>
+    </html>
+  )
+}
+"#;
+    fs::write(project_dir.join("app/root.tsx"), root)?;
+
+    // app/routes/_index.tsx
+    let index = format!(
+        r#"import type {{ MetaFunction }} from '@remix-run/node'
+
+export const meta: MetaFunction = () => [{{ title: '{}' }}]
+
+export default function Index() {{
+  return (
+    <main style={{{{ padding: '2rem' }}}}>
+      <h1>{}</h1>
+      <p>Welcome to your new Remix app!</p>
+    </main>
+  )
+}}
+"#,
+        name, name
+    );
+    fs::write(project_dir.join("app/routes/_index.tsx"), index)?;
+
+    // .gitignore
+    let gitignore = r#"node_modules/
+build/
+.cache/
+*.log
+.env
+.env.local
+"#;
+    fs::write(project_dir.join(".gitignore"), gitignore)?;
+
+    println!("  {} package.json", "✓".green());
+    println!("  {} vite.config.ts", "✓".green());
+    println!("  {} tsconfig.json", "✓".green());
+    println!("  {} app/root.tsx", "✓".green());
+    println!("  {} app/routes/_index.tsx", "✓".green());
+    println!("  {} .gitignore", "✓".green());
+
+    Ok(())
+}
@@ -2,9 +2,34 @@
 
 use anyhow::{Context, Result, bail};
 use colored::Colorize;
-use std::path::Path;
+use indexmap::IndexMap;
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use super::layers::find_layer_violations;
+use crate::dag::build_dag;
+use crate::import_scanner::scan_imports_by_file;
+use crate::manifest::{MANIFEST_FILE, Manifest};
+use crate::pnpm::WorkspacePackage;
+
+/// An app depending on another app
+#[derive(Debug, PartialEq)]
+pub(super) struct ArchViolation {
+    /// Importing file, or the app's package.json for a declared dependency
+    pub source: String,
+    /// Offending workspace package name
+    pub dependency: String,
+}
+
+/// Imports found in one file of a workspace app
+pub(super) struct FileImports {
+    /// Workspace path of the app the file belongs to
+    pub app: String,
+    pub file: PathBuf,
+    pub packages: BTreeSet<String>,
+}
+
 /// Validate dependency architecture rules
 /// Checks that apps only depend on libs (public API), and no cross-app dependencies exist
 pub fn validate_dependencies() -> Result<()> {
@@ -19,6 +44,16 @@ pub fn validate_dependencies_impl(quiet: bool) -> Result<()> {
         );
     }
 
+    let violations = check_workspace_boundaries(quiet)?;
+    if !violations.is_empty() {
+        let lines: Vec<String> = violations.iter().map(|v| format!("  {}", v)).collect();
+        bail!(
+            "{} architecture violation(s) (allow exceptions in [arch.allow])\n{}",
+            violations.len(),
+            lines.join("\n")
+        );
+    }
+
     // Check if dependency-cruiser config exists
     let config_path = Path::new("tools/dependency-cruiser.cjs");
     if !config_path.exists() {
@@ -76,3 +111,165 @@ pub fn validate_dependencies_impl(quiet: bool) -> Result<()> {
     }
     Ok(())
 }
+
+/// Check architecture rules using the lockfile's workspace map: `[arch.layers]`
+/// when configured, otherwise the apps → libs only rule.
+///
+/// Returns one line per violation. Skipped (no violations) when manifest.toml
+/// or a supported lockfile is missing.
+fn check_workspace_boundaries(quiet: bool) -> Result<Vec<String>> {
+    let manifest_path = Path::new(MANIFEST_FILE);
+    if !manifest_path.exists() {
+        return Ok(Vec::new());
+    }
+    let manifest = Manifest::load(manifest_path)?;
+
+    let workspace = match crate::lockfile::load_workspace_map(
+        Path::new("."),
+        &manifest.workspace.package_manager,
+    ) {
+        Ok(map) => map,
+        Err(e) => {
+            if !quiet {
+                println!(
+                    "  {} Workspace boundary check skipped: {}",
+                    "⏭️".yellow(),
+                    e
+                );
+            }
+            return Ok(Vec::new());
+        }
+    };
+
+    if !manifest.arch.layers.is_empty() {
+        let dag = build_dag(&workspace);
+        let violations = find_layer_violations(&dag, &manifest.arch.layers, &manifest.arch.allow)?;
+        if violations.is_empty() && !quiet {
+            println!(
+                "  {} No layer violations across {} tier(s)",
+                "✅".green(),
+                manifest.arch.layers.len()
+            );
+        }
+        return Ok(violations.iter().map(ToString::to_string).collect());
+    }
+
+    let apps = app_paths(&manifest, &workspace);
+    let mut imports = Vec::new();
+    for app in &apps {
+        for (file, packages) in scan_imports_by_file(Path::new(app))? {
+            imports.push(FileImports {
+                app: app.clone(),
+                file,
+                packages,
+            });
+        }
+    }
+
+    let violations = find_violations(&workspace, &apps, &imports, &manifest.arch.allow);
+    if violations.is_empty() && !quiet {
+        println!(
+            "  {} No app → app dependencies across {} app(s)",
+            "✅".green(),
+            apps.len()
+        );
+    }
+    Ok(violations
+        .iter()
+        .map(|v| format!("{} → {}", v.source, v.dependency))
+        .collect())
+}
+
+/// Workspace paths that are apps: `[[app]]` entries not marked `kind = "lib"`,
+/// plus undeclared packages under `apps/`
+pub(super) fn app_paths(
+    manifest: &Manifest,
+    workspace: &HashMap<String, WorkspacePackage>,
+) -> Vec<String> {
+    let mut apps: Vec<String> = workspace
+        .keys()
+        .filter(|path| {
+            match manifest
+                .app
+                .iter()
+                .find(|a| a.path.as_deref() == Some(path.as_str()))
+            {
+                Some(app) => app.kind.as_deref() != Some("lib"),
+                None => path.starts_with("apps/"),
+            }
+        })
+        .cloned()
+        .collect();
+    apps.sort();
+    apps
+}
+
+/// Whether `[arch.allow]` permits the `from` → `to` edge. Each side is a
+/// (workspace path, package name) pair; either form may be used as a key or value.
+pub(super) fn is_allowed_edge(
+    allow: &IndexMap<String, Vec<String>>,
+    from: (&str, &str),
+    to: (&str, &str),
+) -> bool {
+    [from.0, from.1].iter().any(|key| {
+        allow
+            .get(*key)
+            .is_some_and(|allowed| allowed.iter().any(|a| a == to.0 || a == to.1))
+    })
+}
+
+/// Find app → app edges, both declared in package.json and imported in source.
+///
+/// Imports are classified with the workspace map: a package name that isn't a
+/// workspace package is external (e.g. `react`) and never a violation.
+pub(super) fn find_violations(
+    workspace: &HashMap<String, WorkspacePackage>,
+    apps: &[String],
+    imports: &[FileImports],
+    allow: &IndexMap<String, Vec<String>>,
+) -> Vec<ArchViolation> {
+    let by_name: HashMap<&str, &str> = workspace
+        .iter()
+        .map(|(path, pkg)| (pkg.name.as_str(), path.as_str()))
+        .collect();
+    let is_app = |path: &str| apps.iter().any(|a| a == path);
+    let is_allowed = |from: &str, to: &str| {
+        is_allowed_edge(
+            allow,
+            (from, workspace[from].name.as_str()),
+            (to, workspace[to].name.as_str()),
+        )
+    };
+    let crosses = |from: &str, to: &str| is_app(to) && from != to && !is_allowed(from, to);
+
+    let mut violations = Vec::new();
+    for app in apps {
+        let Some(pkg) = workspace.get(app) else {
+            continue;
+        };
+        for dep in &pkg.workspace_deps {
+            if workspace.contains_key(dep) && crosses(app, dep) {
+                violations.push(ArchViolation {
+                    source: format!("{}/package.json", app),
+                    dependency: workspace[dep].name.clone(),
+                });
+            }
+        }
+    }
+
+    for file in imports {
+        for package in &file.packages {
+            if let Some(target) = by_name.get(package.as_str())
+                && workspace.contains_key(&file.app)
+                && crosses(&file.app, target)
+            {
+                violations.push(ArchViolation {
+                    source: file.file.display().to_string(),
+                    dependency: package.clone(),
+                });
+            }
+        }
+    }
+
+    violations
+}
@@ -0,0 +1,127 @@
+//! Layered architecture validation: `[[arch.layers]]` tier ordering
+
+use anyhow::{Context, Result};
+use glob::Pattern;
+use indexmap::IndexMap;
+use std::collections::HashSet;
+use std::fmt;
+
+use super::deps::is_allowed_edge;
+use crate::dag::Dag;
+use crate::manifest::ArchLayer;
+
+/// A dependency chain from one tier into a forbidden tier
+#[derive(Debug, PartialEq)]
+pub(super) struct LayerViolation {
+    /// Package paths from the offending package to the forbidden one
+    pub chain: Vec<String>,
+    pub from_layer: String,
+    pub to_layer: String,
+}
+
+impl fmt::Display for LayerViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({} must not depend on {})",
+            self.chain.join(" → "),
+            self.from_layer,
+            self.to_layer
+        )
+    }
+}
+
+/// Find dependencies that point into a higher tier, or within an isolated tier.
+///
+/// Each layered package is walked through the DAG until the next layered
+/// package, so an edge routed through unlayered packages is still caught and
+/// reported with the full chain.
+pub(super) fn find_layer_violations(
+    dag: &Dag,
+    layers: &[ArchLayer],
+    allow: &IndexMap<String, Vec<String>>,
+) -> Result<Vec<LayerViolation>> {
+    let patterns = compile_layers(layers)?;
+    let tier_of = |path: &str| {
+        patterns
+            .iter()
+            .position(|tier| tier.iter().any(|p| p.matches(path)))
+    };
+
+    let mut violations = Vec::new();
+    for (id, node) in &dag.nodes {
+        let Some(from_tier) = tier_of(id) else {
+            continue;
+        };
+
+        let mut seen: HashSet<&str> = HashSet::new();
+        let mut stack: Vec<Vec<&str>> = node
+            .deps
+            .iter()
+            .map(|dep| vec![id.as_str(), dep.as_str()])
+            .collect();
+
+        while let Some(chain) = stack.pop() {
+            let current = chain[chain.len() - 1];
+            if !seen.insert(current) {
+                continue;
+            }
+            let Some(dep_node) = dag.get(current) else {
+                continue;
+            };
+
+            match tier_of(current) {
+                Some(to_tier) => {
+                    let forbidden = to_tier < from_tier
+                        || (to_tier == from_tier
+                            && layers[to_tier].isolated
+                            && current != id.as_str());
+                    let allowed = is_allowed_edge(
+                        allow,
+                        (id.as_str(), node.name.as_str()),
+                        (current, dep_node.name.as_str()),
+                    );
+                    if forbidden && !allowed {
+                        violations.push(LayerViolation {
+                            chain: chain.iter().map(|s| s.to_string()).collect(),
+                            from_layer: layers[from_tier].name.clone(),
+                            to_layer: layers[to_tier].name.clone(),
+                        });
+                    }
+                }
+                None => {
+                    for dep in &dep_node.deps {
+                        if !chain.contains(&dep.as_str()) {
+                            let mut next = chain.clone();
+                            next.push(dep.as_str());
+                            stack.push(next);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    violations.sort_by(|a, b| a.chain.cmp(&b.chain));
+    Ok(violations)
+}
+
+fn compile_layers(layers: &[ArchLayer]) -> Result<Vec<Vec<Pattern>>> {
+    layers
+        .iter()
+        .map(|layer| {
+            layer
+                .paths
+                .iter()
+                .map(|p| {
+                    Pattern::new(p).with_context(|| {
+                        format!(
+                            "Invalid path pattern in [arch.layers] '{}': {}",
+                            layer.name, p
+                        )
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
@@ -1,12 +1,13 @@
 //! Manifest validation: syntax, app paths, port conflicts, required env vars, env patterns
 
-use anyhow::{Context, Result, bail};
+use anyhow::{Context, Result};
 use colored::Colorize;
 use regex::Regex;
 use std::collections::HashSet;
 use std::fs;
 use std::path::Path;
 
+use crate::error::AirisError;
 use crate::manifest::{MANIFEST_FILE, Manifest};
 
 /// Validate manifest.toml: syntax, app paths, port conflicts, required env vars
@@ -21,9 +22,7 @@ pub fn validate_manifest_impl(quiet: bool) -> Result<()> {
 
     let manifest_path = Path::new(MANIFEST_FILE);
     if !manifest_path.exists() {
-        bail!(
-            "manifest.toml not found. Create one (see docs/manifest.md) or ask Claude Code via /airis:init."
-        );
+        return Err(AirisError::ManifestMissing.into());
     }
 
     // 1. Syntax validation (parse TOML)
@@ -96,7 +95,11 @@ pub fn validate_manifest_impl(quiet: bool) -> Result<()> {
     failures += pattern_failures;
 
     if failures > 0 {
-        bail!("manifest.toml validation failed with {} errors", failures);
+        return Err(AirisError::ValidationFailed {
+            what: "manifest.toml validation",
+            count: failures,
+        }
+        .into());
     }
 
     if !quiet {
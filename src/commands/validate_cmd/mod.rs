@@ -4,6 +4,7 @@
 
 mod deps;
 mod env;
+mod layers;
 mod manifest_check;
 mod networks;
 mod ports;
@@ -11,10 +12,12 @@ mod ports;
 #[cfg(test)]
 mod tests;
 
-use anyhow::{Result, bail};
+use anyhow::Result;
 use colored::Colorize;
 use serde::Serialize;
 
+use crate::error::AirisError;
+
 pub use deps::{validate_dependencies, validate_dependencies_impl};
 pub use env::{validate_env, validate_env_impl};
 pub use manifest_check::{validate_manifest, validate_manifest_impl};
@@ -99,7 +102,11 @@ fn run_human(action: ValidateAction) -> Result<()> {
             }
 
             if failures > 0 {
-                bail!("Validation failed with {} errors", failures);
+                return Err(AirisError::ValidationFailed {
+                    what: "Validation",
+                    count: failures,
+                }
+                .into());
             }
 
             println!();
@@ -201,7 +208,11 @@ fn run_json(action: ValidateAction) -> Result<()> {
     println!("{}", serde_json::to_string_pretty(&result)?);
 
     if !all_passed {
-        std::process::exit(1);
+        let err = AirisError::ValidationFailed {
+            what: "Validation",
+            count: failed_count,
+        };
+        std::process::exit(err.exit_code());
     }
 
     Ok(())
@@ -116,6 +116,13 @@ pub fn validate_networks_impl(quiet: bool) -> Result<()> {
             }
             failures += 1;
         }
+
+        // Per-service routing: unreachable web services, routers without Host()
+        if !quiet {
+            for warning in service_routing_warnings(&content, &proxy_network) {
+                println!("  {} {}: {}", "⚠️".yellow(), project, warning);
+            }
+        }
     }
 
     if failures > 0 {
@@ -131,3 +138,130 @@ pub fn validate_networks_impl(quiet: bool) -> Result<()> {
     }
     Ok(())
 }
+
+/// Warn about services that Traefik can't route to.
+///
+/// - A service exposing an HTTP-ish port without `traefik.enable=true` and
+///   without joining the proxy network is unreachable through the proxy.
+/// - A `traefik.enable=true` service without a `Host()` router rule gets no route.
+pub(super) fn service_routing_warnings(compose_yaml: &str, proxy_network: &str) -> Vec<String> {
+    let Ok(compose) = serde_yaml_ng::from_str::<serde_yaml_ng::Value>(compose_yaml) else {
+        return Vec::new();
+    };
+    let Some(services) = compose.get("services").and_then(|s| s.as_mapping()) else {
+        return Vec::new();
+    };
+
+    let mut warnings = Vec::new();
+    for (name, service) in services {
+        let Some(name) = name.as_str() else {
+            continue;
+        };
+        let labels = service_labels(service);
+        let traefik_enabled = labels
+            .iter()
+            .any(|(k, v)| k == "traefik.enable" && v == "true");
+
+        if traefik_enabled {
+            let has_host_rule = labels
+                .iter()
+                .any(|(k, v)| k.starts_with("traefik.http.routers.") && v.contains("Host("));
+            if !has_host_rule {
+                warnings.push(format!(
+                    "service '{}' has traefik.enable=true but no Host() router rule",
+                    name
+                ));
+            }
+            continue;
+        }
+
+        let on_proxy = service_networks(service)
+            .iter()
+            .any(|n| n == "proxy" || (!proxy_network.is_empty() && n == proxy_network));
+        if on_proxy {
+            continue;
+        }
+        if let Some(port) = container_ports(service)
+            .into_iter()
+            .find(|p| is_http_port(*p))
+        {
+            warnings.push(format!(
+                "service '{}' exposes port {} but has no Traefik labels and isn't on the proxy network",
+                name, port
+            ));
+        }
+    }
+    warnings
+}
+
+/// Ports that usually serve HTTP in dev stacks
+fn is_http_port(port: u16) -> bool {
+    matches!(port, 80 | 443 | 3000..=3999 | 5173 | 8000..=8999)
+}
+
+/// Labels as key/value pairs, from either the list (`- k=v`) or map form
+fn service_labels(service: &serde_yaml_ng::Value) -> Vec<(String, String)> {
+    match service.get("labels") {
+        Some(serde_yaml_ng::Value::Sequence(items)) => items
+            .iter()
+            .filter_map(|item| item.as_str())
+            .map(|item| match item.split_once('=') {
+                Some((k, v)) => (k.to_string(), v.to_string()),
+                None => (item.to_string(), String::new()),
+            })
+            .collect(),
+        Some(serde_yaml_ng::Value::Mapping(map)) => map
+            .iter()
+            .filter_map(|(k, v)| {
+                let value = match v {
+                    serde_yaml_ng::Value::String(s) => s.clone(),
+                    serde_yaml_ng::Value::Bool(b) => b.to_string(),
+                    serde_yaml_ng::Value::Number(n) => n.to_string(),
+                    _ => return None,
+                };
+                Some((k.as_str()?.to_string(), value))
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Network names from either the list or map form of `networks:`
+fn service_networks(service: &serde_yaml_ng::Value) -> Vec<String> {
+    match service.get("networks") {
+        Some(serde_yaml_ng::Value::Sequence(items)) => items
+            .iter()
+            .filter_map(|n| n.as_str().map(str::to_string))
+            .collect(),
+        Some(serde_yaml_ng::Value::Mapping(map)) => map
+            .keys()
+            .filter_map(|n| n.as_str().map(str::to_string))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Container-side ports from `ports:` (short or long syntax) and `expose:`
+fn container_ports(service: &serde_yaml_ng::Value) -> Vec<u16> {
+    let entries = ["ports", "expose"]
+        .iter()
+        .filter_map(|key| service.get(*key).and_then(|v| v.as_sequence()))
+        .flatten();
+
+    entries
+        .filter_map(|entry| match entry {
+            serde_yaml_ng::Value::Number(n) => n.as_u64().and_then(|p| u16::try_from(p).ok()),
+            serde_yaml_ng::Value::String(s) => {
+                // "127.0.0.1:8080:3000/tcp" → "3000"; ranges use their first port
+                let container = s.rsplit(':').next()?;
+                let container = container.split('/').next()?;
+                container.split('-').next()?.parse().ok()
+            }
+            serde_yaml_ng::Value::Mapping(_) => entry
+                .get("target")
+                .and_then(|t| t.as_u64())
+                .and_then(|p| u16::try_from(p).ok()),
+            _ => None,
+        })
+        .collect()
+}
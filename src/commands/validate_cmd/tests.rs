@@ -1,2 +1,251 @@
-// Tests for validate_cmd module
-// Currently no tests defined; placeholder for future test additions.
+//! Tests for validate_cmd module
+
+use indexmap::IndexMap;
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+
+use super::deps::{ArchViolation, FileImports, app_paths, find_violations};
+use super::layers::{LayerViolation, find_layer_violations};
+use super::networks::service_routing_warnings;
+use crate::dag::build_dag;
+use crate::manifest::{ArchLayer, Manifest};
+use crate::pnpm::WorkspacePackage;
+
+fn workspace() -> HashMap<String, WorkspacePackage> {
+    let pkg = |name: &str, deps: &[&str]| WorkspacePackage {
+        name: name.to_string(),
+        workspace_deps: deps.iter().map(|d| d.to_string()).collect(),
+    };
+    HashMap::from([
+        ("apps/web".to_string(), pkg("@acme/web", &["libs/ui"])),
+        ("apps/admin".to_string(), pkg("@acme/admin", &[])),
+        ("libs/ui".to_string(), pkg("@acme/ui", &[])),
+    ])
+}
+
+fn imports(app: &str, file: &str, packages: &[&str]) -> FileImports {
+    FileImports {
+        app: app.to_string(),
+        file: PathBuf::from(file),
+        packages: packages
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<BTreeSet<_>>(),
+    }
+}
+
+fn apps() -> Vec<String> {
+    vec!["apps/admin".to_string(), "apps/web".to_string()]
+}
+
+#[test]
+fn test_app_importing_lib_and_external_passes() {
+    let files = [imports(
+        "apps/web",
+        "apps/web/src/page.tsx",
+        &["@acme/ui", "react", "@tanstack/react-query"],
+    )];
+    let violations = find_violations(&workspace(), &apps(), &files, &IndexMap::new());
+    assert!(violations.is_empty());
+}
+
+#[test]
+fn test_app_importing_sibling_app_fails_with_file() {
+    let files = [imports(
+        "apps/web",
+        "apps/web/src/page.tsx",
+        &["@acme/admin", "react"],
+    )];
+    let violations = find_violations(&workspace(), &apps(), &files, &IndexMap::new());
+    assert_eq!(
+        violations,
+        vec![ArchViolation {
+            source: "apps/web/src/page.tsx".to_string(),
+            dependency: "@acme/admin".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_declared_app_dependency_fails() {
+    let mut workspace = workspace();
+    workspace
+        .get_mut("apps/web")
+        .unwrap()
+        .workspace_deps
+        .push("apps/admin".to_string());
+
+    let violations = find_violations(&workspace, &apps(), &[], &IndexMap::new());
+    assert_eq!(
+        violations,
+        vec![ArchViolation {
+            source: "apps/web/package.json".to_string(),
+            dependency: "@acme/admin".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_allowlist_permits_edge_by_path_or_name() {
+    let files = [imports(
+        "apps/web",
+        "apps/web/src/page.tsx",
+        &["@acme/admin"],
+    )];
+
+    let by_path = IndexMap::from([("apps/web".to_string(), vec!["apps/admin".to_string()])]);
+    assert!(find_violations(&workspace(), &apps(), &files, &by_path).is_empty());
+
+    let by_name = IndexMap::from([("@acme/web".to_string(), vec!["@acme/admin".to_string()])]);
+    assert!(find_violations(&workspace(), &apps(), &files, &by_name).is_empty());
+}
+
+#[test]
+fn test_app_paths_respects_manifest_kind() {
+    let manifest: Manifest = toml::from_str(
+        r#"
+version = 1
+[project]
+id = "test"
+
+[[app]]
+name = "shared"
+path = "apps/admin"
+kind = "lib"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(app_paths(&manifest, &workspace()), vec!["apps/web"]);
+}
+
+// ── [arch.layers] ──
+
+fn layers() -> Vec<ArchLayer> {
+    let layer = |name: &str, paths: &[&str], isolated: bool| ArchLayer {
+        name: name.to_string(),
+        paths: paths.iter().map(|p| p.to_string()).collect(),
+        isolated,
+    };
+    vec![
+        layer("apps", &["apps/*"], true),
+        layer("infra", &["libs/infra*"], false),
+        layer("domain", &["libs/domain*"], false),
+    ]
+}
+
+fn layered_workspace(edges: &[(&str, &[&str])]) -> HashMap<String, WorkspacePackage> {
+    edges
+        .iter()
+        .map(|(path, deps)| {
+            (
+                path.to_string(),
+                WorkspacePackage {
+                    name: format!("@acme/{}", path.rsplit('/').next().unwrap()),
+                    workspace_deps: deps.iter().map(|d| d.to_string()).collect(),
+                },
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_layers_allow_downward_dependencies() {
+    let workspace = layered_workspace(&[
+        ("apps/web", &["libs/infra", "libs/domain"]),
+        ("libs/infra", &["libs/domain"]),
+        ("libs/domain", &[]),
+    ]);
+    let violations =
+        find_layer_violations(&build_dag(&workspace), &layers(), &IndexMap::new()).unwrap();
+    assert!(violations.is_empty());
+}
+
+#[test]
+fn test_layers_report_upward_chain_through_unlayered_package() {
+    let workspace = layered_workspace(&[
+        ("libs/domain", &["libs/shared"]),
+        ("libs/shared", &["libs/infra"]),
+        ("libs/infra", &[]),
+    ]);
+    let violations =
+        find_layer_violations(&build_dag(&workspace), &layers(), &IndexMap::new()).unwrap();
+    assert_eq!(
+        violations,
+        vec![LayerViolation {
+            chain: vec![
+                "libs/domain".to_string(),
+                "libs/shared".to_string(),
+                "libs/infra".to_string(),
+            ],
+            from_layer: "domain".to_string(),
+            to_layer: "infra".to_string(),
+        }]
+    );
+    assert_eq!(
+        violations[0].to_string(),
+        "libs/domain → libs/shared → libs/infra (domain must not depend on infra)"
+    );
+}
+
+#[test]
+fn test_layers_isolated_tier_and_allowlist() {
+    let workspace = layered_workspace(&[("apps/web", &["apps/admin"]), ("apps/admin", &[])]);
+    let dag = build_dag(&workspace);
+
+    let violations = find_layer_violations(&dag, &layers(), &IndexMap::new()).unwrap();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].from_layer, "apps");
+
+    let allow = IndexMap::from([("@acme/web".to_string(), vec!["apps/admin".to_string()])]);
+    assert!(
+        find_layer_violations(&dag, &layers(), &allow)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+// ── service routing ──
+
+#[test]
+fn test_routing_warns_on_unrouted_web_service() {
+    let compose = r#"
+services:
+  web:
+    image: node:22
+    ports: ["3000:3000"]
+  routed:
+    image: node:22
+    ports: ["3001:3001"]
+    networks: [proxy]
+  db:
+    image: postgres:16
+    ports: ["5432:5432"]
+"#;
+    let warnings = service_routing_warnings(compose, "traefik-public");
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("'web' exposes port 3000"));
+}
+
+#[test]
+fn test_routing_warns_on_traefik_service_without_host_rule() {
+    let compose = r#"
+services:
+  api:
+    image: node:22
+    expose: ["8080"]
+    labels:
+      - traefik.enable=true
+      - traefik.http.services.api.loadbalancer.server.port=8080
+  app:
+    image: node:22
+    labels:
+      traefik.enable: true
+      traefik.http.routers.app.rule: Host(`app.localhost`)
+"#;
+    let warnings = service_routing_warnings(compose, "");
+    assert_eq!(
+        warnings,
+        vec!["service 'api' has traefik.enable=true but no Host() router rule"]
+    );
+}
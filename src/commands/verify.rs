@@ -1,16 +1,29 @@
 //! Verify command: quality and health checks for Docker-first environments
 //!
 //! Executes verification rules from manifest.toml inside the Docker workspace.
-//! Supports global [rule.verify] and app-specific stack-based verify commands.
+//! Supports global [rule.verify] and app-specific stack-based verify commands,
+//! followed by project-specific [verify.checks] run on the host. With
+//! `--live`, each app's health endpoint on the running stack is probed too.
 
-use crate::manifest::Manifest;
+use crate::error::AirisError;
+use crate::manifest::{MANIFEST_FILE, Manifest, VerifyCheck};
 use anyhow::{Context, Result};
 use colored::Colorize;
 use std::process::Command;
+use std::time::{Duration, Instant};
+
+/// Per-endpoint timeout for `--live` health probes
+const LIVE_PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Maximum number of health probes in flight at once
+const MAX_CONCURRENT_PROBES: usize = 4;
 
 /// Run the verify command
-pub fn run() -> Result<()> {
-    let manifest = Manifest::load("manifest.toml")
+pub fn run(live: bool) -> Result<()> {
+    if !std::path::Path::new(MANIFEST_FILE).exists() {
+        return Err(AirisError::ManifestMissing.into());
+    }
+    let manifest = Manifest::load(MANIFEST_FILE)
         .with_context(|| "Failed to load manifest.toml for verification")?;
 
     println!(
@@ -20,8 +33,16 @@ pub fn run() -> Result<()> {
     println!();
 
     // 1. Check if workspace container is running
-    let container = find_workspace_container();
-    let can_exec = if container.is_none() {
+    let docker = crate::docker::ensure_available();
+    let container = docker
+        .as_ref()
+        .ok()
+        .and_then(|()| find_workspace_container());
+    let can_exec = if let Err(e) = &docker {
+        println!("{}", format!("⚠️  {}", e).yellow());
+        println!("   Runtime checks will be skipped.");
+        false
+    } else if container.is_none() {
         println!(
             "{}",
             "⚠️  Workspace container not running. Runtime checks will be skipped.".yellow()
@@ -103,6 +124,22 @@ pub fn run() -> Result<()> {
         }
     }
 
+    // C. Custom [verify.checks] (host-side, after built-in checks)
+    if !manifest.verify.checks.is_empty() {
+        println!("\n{}", "🧪 Custom Checks".bold());
+        for (name, check) in &manifest.verify.checks {
+            if !run_custom_check(name, check)? {
+                failures += 1;
+            }
+        }
+    }
+
+    // D. Live health endpoints on the running stack
+    if live {
+        println!("\n{}", "🌐 Live Health".bold());
+        failures += run_live_checks(&manifest);
+    }
+
     println!();
 
     // 3. Final result
@@ -112,7 +149,11 @@ pub fn run() -> Result<()> {
             "   {}",
             "Fix the errors above before committing or finishing the task.".yellow()
         );
-        std::process::exit(1);
+        return Err(AirisError::ValidationFailed {
+            what: "Verification",
+            count: failures,
+        }
+        .into());
     }
 
     println!(
@@ -132,6 +173,7 @@ fn run_verify_command(container: &str, cmd: &str) -> Result<bool> {
     let status = Command::new("docker")
         .args(["exec", container, "sh", "-c", cmd])
         .status()
+        .map_err(|e| AirisError::DockerUnavailable(e.to_string()))
         .with_context(|| format!("Failed to execute verification command: {}", cmd))?;
 
     if status.success() {
@@ -147,6 +189,171 @@ fn run_verify_command(container: &str, cmd: &str) -> Result<bool> {
     }
 }
 
+/// Run a `[verify.checks]` entry on the host and report pass/fail
+fn run_custom_check(name: &str, check: &VerifyCheck) -> Result<bool> {
+    println!("{} {}: {}", "→".dimmed(), name.bold(), check.command.cyan());
+
+    let status = Command::new("sh")
+        .args(["-c", &check.command])
+        .status()
+        .with_context(|| format!("Failed to execute check '{}': {}", name, check.command))?;
+
+    if check_passed(status.success(), check.expect_success) {
+        println!("   {} Check passed", "✅".green());
+        return Ok(true);
+    }
+
+    let expectation = if check.expect_success {
+        "expected success"
+    } else {
+        "expected failure"
+    };
+    println!(
+        "   {} Check failed ({}, exit code: {:?})",
+        "✗".red(),
+        expectation,
+        status.code()
+    );
+    if let Some(message) = &check.message {
+        println!("     {}", message.yellow());
+    }
+    Ok(false)
+}
+
+/// An app health endpoint probed by `verify --live`
+#[derive(Debug, PartialEq)]
+struct LiveEndpoint {
+    app: String,
+    url: String,
+}
+
+/// Outcome of a single health probe
+struct ProbeResult {
+    status: Option<u16>,
+    latency: Duration,
+    error: Option<String>,
+}
+
+impl ProbeResult {
+    fn healthy(&self) -> bool {
+        self.status.is_some_and(|s| (200..400).contains(&s))
+    }
+}
+
+/// Health URLs for every app `airis gen` publishes a port for.
+///
+/// The port is the framework convention published by the generated
+/// compose.yaml; the path is `[app.deploy].health_path` or the framework default.
+fn live_endpoints(manifest: &Manifest) -> Vec<LiveEndpoint> {
+    manifest
+        .app
+        .iter()
+        .filter(|app| app.path.is_some() && app.kind.as_deref() != Some("lib"))
+        .filter_map(|app| {
+            let framework = app.framework.as_deref().unwrap_or("node");
+            let defaults = crate::conventions::framework_defaults(framework);
+            if defaults.port == 0 {
+                return None;
+            }
+            let health_path = app
+                .deploy
+                .as_ref()
+                .and_then(|d| d.health_path.as_deref())
+                .unwrap_or(defaults.health_path);
+            Some(LiveEndpoint {
+                app: app.name.clone(),
+                url: format!("http://localhost:{}{}", defaults.port, health_path),
+            })
+        })
+        .collect()
+}
+
+/// Probe each app's health endpoint and print a status table.
+///
+/// Returns the number of unhealthy endpoints.
+fn run_live_checks(manifest: &Manifest) -> usize {
+    let endpoints = live_endpoints(manifest);
+    if endpoints.is_empty() {
+        println!("   {}", "No apps with a published port.".dimmed());
+        return 0;
+    }
+
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(LIVE_PROBE_TIMEOUT))
+        .http_status_as_error(false)
+        .build()
+        .into();
+
+    let mut results = Vec::with_capacity(endpoints.len());
+    for batch in endpoints.chunks(MAX_CONCURRENT_PROBES) {
+        let batch_results: Vec<ProbeResult> = std::thread::scope(|scope| {
+            let handles: Vec<_> = batch
+                .iter()
+                .map(|endpoint| {
+                    let agent = agent.clone();
+                    scope.spawn(move || probe_endpoint(&agent, &endpoint.url))
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| {
+                    h.join().unwrap_or_else(|_| ProbeResult {
+                        status: None,
+                        latency: Duration::ZERO,
+                        error: Some("probe panicked".to_string()),
+                    })
+                })
+                .collect()
+        });
+        results.extend(batch_results);
+    }
+
+    let width = endpoints.iter().map(|e| e.app.len()).max().unwrap_or(0);
+    let mut failures = 0;
+    for (endpoint, result) in endpoints.iter().zip(&results) {
+        let status = match (result.status, &result.error) {
+            (Some(code), _) => code.to_string(),
+            (None, Some(err)) => err.clone(),
+            (None, None) => "no response".to_string(),
+        };
+        let line = format!(
+            "{:<width$}  {:<40}  {:>6}ms  {}",
+            endpoint.app,
+            endpoint.url,
+            result.latency.as_millis(),
+            status,
+        );
+        if result.healthy() {
+            println!("   {} {}", "✅".green(), line);
+        } else {
+            println!("   {} {}", "✗".red(), line.red());
+            failures += 1;
+        }
+    }
+    failures
+}
+
+fn probe_endpoint(agent: &ureq::Agent, url: &str) -> ProbeResult {
+    let started = Instant::now();
+    match agent.get(url).call() {
+        Ok(response) => ProbeResult {
+            status: Some(response.status().as_u16()),
+            latency: started.elapsed(),
+            error: None,
+        },
+        Err(err) => ProbeResult {
+            status: None,
+            latency: started.elapsed(),
+            error: Some(err.to_string()),
+        },
+    }
+}
+
+/// A check passes when the command outcome matches `expect_success`
+fn check_passed(succeeded: bool, expect_success: bool) -> bool {
+    succeeded == expect_success
+}
+
 /// Wrap a shell argument in single quotes, escaping any embedded single quotes.
 fn shell_quote(s: &str) -> String {
     format!("'{}'", s.replace('\'', "'\\''"))
@@ -168,3 +375,86 @@ fn find_workspace_container() -> Option<String> {
     let names = String::from_utf8_lossy(&output.stdout);
     names.lines().next().map(|s| s.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_live_endpoints_use_convention_port_and_health_path() {
+        let manifest: Manifest = toml::from_str(
+            r#"
+version = 1
+[project]
+id = "test"
+
+[[app]]
+name = "web"
+path = "apps/web"
+framework = "nextjs"
+
+[[app]]
+name = "api"
+path = "apps/api"
+framework = "hono"
+deploy = { health_path = "/ready" }
+
+[[app]]
+name = "ui"
+path = "libs/ui"
+kind = "lib"
+"#,
+        )
+        .unwrap();
+
+        let endpoints = live_endpoints(&manifest);
+        let web = crate::conventions::framework_defaults("nextjs");
+        let api = crate::conventions::framework_defaults("hono");
+        assert_eq!(
+            endpoints,
+            vec![
+                LiveEndpoint {
+                    app: "web".to_string(),
+                    url: format!("http://localhost:{}{}", web.port, web.health_path),
+                },
+                LiveEndpoint {
+                    app: "api".to_string(),
+                    url: format!("http://localhost:{}/ready", api.port),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_probe_result_healthy_range() {
+        let result = |status| ProbeResult {
+            status,
+            latency: Duration::ZERO,
+            error: None,
+        };
+        assert!(result(Some(200)).healthy());
+        assert!(result(Some(302)).healthy());
+        assert!(!result(Some(503)).healthy());
+        assert!(!result(None).healthy());
+    }
+
+    #[test]
+    fn test_check_passed_honors_expectation() {
+        assert!(check_passed(true, true));
+        assert!(check_passed(false, false));
+        assert!(!check_passed(false, true));
+        assert!(!check_passed(true, false));
+    }
+
+    #[test]
+    fn test_run_custom_check() {
+        let check = |command: &str, expect_success: bool| VerifyCheck {
+            command: command.to_string(),
+            expect_success,
+            message: None,
+        };
+        assert!(run_custom_check("ok", &check("true", true)).unwrap());
+        assert!(!run_custom_check("fail", &check("false", true)).unwrap());
+        assert!(run_custom_check("inverted", &check("exit 3", false)).unwrap());
+    }
+}
@@ -69,6 +69,40 @@ pub fn framework_defaults(framework: &str) -> FrameworkDefaults {
             isolated_dirs: &["dist", "node_modules", ".turbo", ".cache"],
             global_caches: &[],
         },
+        "sveltekit" => FrameworkDefaults {
+            port: 5173,
+            health_path: "/",
+            entrypoint: "build/index.js",
+            dev_script: "vite dev",
+            build_script: "vite build",
+            start_script: "node build",
+            docker_env: &[("CHOKIDAR_USEPOLLING", "true")],
+            default_scripts: &[
+                ("dev", "vite dev"),
+                ("build", "vite build"),
+                ("start", "node build"),
+                ("preview", "vite preview"),
+            ],
+            isolated_dirs: &[".svelte-kit", "build", "node_modules", ".turbo", ".cache"],
+            global_caches: &[],
+        },
+        "remix" => FrameworkDefaults {
+            port: 5173,
+            health_path: "/",
+            entrypoint: "build/server/index.js",
+            dev_script: "remix vite:dev",
+            build_script: "remix vite:build",
+            start_script: "remix-serve ./build/server/index.js",
+            docker_env: &[("CHOKIDAR_USEPOLLING", "true")],
+            default_scripts: &[
+                ("dev", "remix vite:dev"),
+                ("build", "remix vite:build"),
+                ("start", "remix-serve ./build/server/index.js"),
+                ("typecheck", "tsc"),
+            ],
+            isolated_dirs: &["build", "node_modules", ".turbo", ".cache"],
+            global_caches: &[],
+        },
         "hono" => FrameworkDefaults {
             port: 3000,
             health_path: "/health",
@@ -274,6 +308,17 @@ mod tests {
         assert_eq!(d.entrypoint, "dist/index.js");
     }
 
+    #[test]
+    fn test_sveltekit_and_remix_defaults() {
+        let svelte = framework_defaults("sveltekit");
+        assert_eq!(svelte.start_script, "node build");
+        assert!(svelte.isolated_dirs.contains(&".svelte-kit"));
+
+        let remix = framework_defaults("remix");
+        assert_eq!(remix.dev_script, "remix vite:dev");
+        assert_eq!(remix.start_script, "remix-serve ./build/server/index.js");
+    }
+
     #[test]
     fn test_python_defaults() {
         let d = framework_defaults("python");
@@ -41,10 +41,13 @@ impl Dag {
 
     /// Get topological order starting from target
     /// Returns nodes in dependency-first order
+    ///
+    /// Fails with the full cycle chain (e.g. `a -> b -> c -> a`) if the
+    /// target reaches a circular dependency.
     pub fn topo_order(&self, target: &str) -> Result<Vec<&DagNode>> {
         let mut visited = HashSet::new();
         let mut order = Vec::new();
-        let mut stack = HashSet::new();
+        let mut stack = Vec::new();
 
         self.visit(target, &mut visited, &mut order, &mut stack)?;
 
@@ -56,30 +59,62 @@ impl Dag {
         id: &str,
         visited: &mut HashSet<String>,
         order: &mut Vec<&'a DagNode>,
-        stack: &mut HashSet<String>,
+        stack: &mut Vec<String>,
     ) -> Result<()> {
         if visited.contains(id) {
             return Ok(());
         }
 
-        if stack.contains(id) {
-            anyhow::bail!("Circular dependency detected: {}", id);
+        if let Some(start) = stack.iter().position(|s| s == id) {
+            let mut chain: Vec<&str> = stack[start..].iter().map(|s| s.as_str()).collect();
+            chain.push(id);
+            anyhow::bail!("Circular dependency detected: {}", chain.join(" -> "));
         }
 
-        stack.insert(id.to_string());
+        stack.push(id.to_string());
 
         if let Some(node) = self.nodes.get(id) {
             for dep_id in &node.deps {
                 self.visit(dep_id, visited, order, stack)?;
             }
-            stack.remove(id);
             visited.insert(id.to_string());
             order.push(node);
         }
+        stack.pop();
 
         Ok(())
     }
 
+    /// Get every node that transitively depends on `id` (reverse reachable set).
+    ///
+    /// The target itself is not included. Results are sorted by ID.
+    pub fn get_dependents(&self, id: &str) -> Vec<&DagNode> {
+        let mut reverse: HashMap<&str, Vec<&str>> = HashMap::new();
+        for node in self.nodes.values() {
+            for dep in &node.deps {
+                reverse
+                    .entry(dep.as_str())
+                    .or_default()
+                    .push(node.id.as_str());
+            }
+        }
+
+        let mut seen: HashSet<&str> = HashSet::new();
+        let mut queue = vec![id];
+        while let Some(current) = queue.pop() {
+            for &dependent in reverse.get(current).into_iter().flatten() {
+                if dependent != id && seen.insert(dependent) {
+                    queue.push(dependent);
+                }
+            }
+        }
+
+        let mut dependents: Vec<&DagNode> =
+            seen.into_iter().filter_map(|d| self.nodes.get(d)).collect();
+        dependents.sort_by(|a, b| a.id.cmp(&b.id));
+        dependents
+    }
+
     /// Get all dependency paths for a target (in build order)
     pub fn get_dep_paths(&self, target: &str) -> Result<Vec<String>> {
         let order = self.topo_order(target)?;
@@ -140,4 +175,83 @@ mod tests {
         assert!(ids.iter().position(|&x| x == "c") < ids.iter().position(|&x| x == "b"));
         assert!(ids.iter().position(|&x| x == "b") < ids.iter().position(|&x| x == "a"));
     }
+
+    fn node(id: &str, deps: &[&str]) -> DagNode {
+        DagNode {
+            id: id.to_string(),
+            name: id.to_string(),
+            path: id.to_string(),
+            deps: deps.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_get_dependents_diamond() {
+        // apps/web -> libs/ui -> libs/core
+        // apps/web -> libs/api-client -> libs/core
+        // apps/admin -> libs/ui
+        let mut dag = Dag::new();
+        dag.add_node(node("apps/web", &["libs/ui", "libs/api-client"]));
+        dag.add_node(node("apps/admin", &["libs/ui"]));
+        dag.add_node(node("libs/ui", &["libs/core"]));
+        dag.add_node(node("libs/api-client", &["libs/core"]));
+        dag.add_node(node("libs/core", &[]));
+        dag.add_node(node("apps/docs", &[]));
+
+        let ids = |id: &str| -> Vec<String> {
+            dag.get_dependents(id)
+                .iter()
+                .map(|n| n.id.clone())
+                .collect()
+        };
+
+        assert_eq!(
+            ids("libs/core"),
+            vec!["apps/admin", "apps/web", "libs/api-client", "libs/ui"]
+        );
+        assert_eq!(ids("libs/api-client"), vec!["apps/web"]);
+        assert!(ids("apps/web").is_empty());
+        assert!(ids("apps/docs").is_empty());
+    }
+
+    #[test]
+    fn test_get_dep_paths_reports_cycle_chain() {
+        let mut workspace_map = HashMap::new();
+        for (path, deps) in [
+            ("apps/web", vec!["libs/a"]),
+            ("libs/a", vec!["libs/b"]),
+            ("libs/b", vec!["libs/c"]),
+            ("libs/c", vec!["libs/a"]),
+        ] {
+            workspace_map.insert(
+                path.to_string(),
+                crate::pnpm::WorkspacePackage {
+                    name: path.to_string(),
+                    workspace_deps: deps.into_iter().map(String::from).collect(),
+                },
+            );
+        }
+        let dag = build_dag(&workspace_map);
+
+        let err = dag.get_dep_paths("apps/web").unwrap_err().to_string();
+        assert!(
+            err.contains("libs/a -> libs/b -> libs/c -> libs/a"),
+            "unexpected error: {err}"
+        );
+        assert!(!err.contains("apps/web"));
+    }
+
+    #[test]
+    fn test_get_dependents_tolerates_cycles() {
+        let mut dag = Dag::new();
+        dag.add_node(node("a", &["b"]));
+        dag.add_node(node("b", &["a"]));
+
+        let ids: Vec<_> = dag
+            .get_dependents("a")
+            .iter()
+            .map(|n| n.id.clone())
+            .collect();
+        assert_eq!(ids, vec!["b"]);
+    }
 }
@@ -0,0 +1,95 @@
+//! Docker preflight for commands that shell out to `docker`
+//!
+//! `ensure_available` runs `docker info` once per process and turns a missing
+//! CLI or a stopped daemon into an actionable message instead of a raw
+//! "command not found" or connection-refused error.
+
+use std::io::ErrorKind;
+use std::process::{Command, Stdio};
+use std::sync::OnceLock;
+
+use crate::error::AirisError;
+
+/// Why Docker can't be used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unavailable {
+    /// The `docker` CLI is not on PATH
+    NotInstalled,
+    /// The CLI exists but the daemon did not answer
+    DaemonDown,
+}
+
+static STATUS: OnceLock<Option<Unavailable>> = OnceLock::new();
+
+/// Check that the docker CLI is installed and its daemon is running.
+///
+/// The check runs once; later calls reuse the result.
+pub fn ensure_available() -> Result<(), AirisError> {
+    match *STATUS.get_or_init(probe) {
+        None => Ok(()),
+        Some(reason) => Err(AirisError::DockerUnavailable(hint(
+            reason,
+            std::env::consts::OS,
+        ))),
+    }
+}
+
+fn probe() -> Option<Unavailable> {
+    let output = Command::new("docker")
+        .args(["info", "--format", "{{.ServerVersion}}"])
+        .stdin(Stdio::null())
+        .output();
+
+    match output {
+        Ok(out) if out.status.success() => None,
+        Ok(_) => Some(Unavailable::DaemonDown),
+        Err(e) if e.kind() == ErrorKind::NotFound => Some(Unavailable::NotInstalled),
+        Err(_) => Some(Unavailable::DaemonDown),
+    }
+}
+
+/// What to do next, for the host OS
+fn hint(reason: Unavailable, os: &str) -> String {
+    match (reason, os) {
+        (Unavailable::NotInstalled, "macos") => "docker CLI not found. Install OrbStack \
+             (`brew install --cask orbstack`, recommended on Apple Silicon) or Docker Desktop."
+            .to_string(),
+        (Unavailable::NotInstalled, "linux") => "docker CLI not found. Install Docker Engine: \
+             https://docs.docker.com/engine/install/"
+            .to_string(),
+        (Unavailable::NotInstalled, _) => {
+            "docker CLI not found. Install Docker Desktop: https://docs.docker.com/get-docker/"
+                .to_string()
+        }
+        (Unavailable::DaemonDown, "macos") => {
+            "the Docker daemon is not running. Start OrbStack or Docker Desktop and retry."
+                .to_string()
+        }
+        (Unavailable::DaemonDown, "linux") => "the Docker daemon is not running. Start it with \
+             `sudo systemctl start docker` (or add your user to the docker group) and retry."
+            .to_string(),
+        (Unavailable::DaemonDown, _) => {
+            "the Docker daemon is not running. Start Docker Desktop and retry.".to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hint_is_keyed_to_os_and_reason() {
+        assert!(hint(Unavailable::NotInstalled, "macos").contains("OrbStack"));
+        assert!(hint(Unavailable::NotInstalled, "linux").contains("Docker Engine"));
+        assert!(hint(Unavailable::DaemonDown, "linux").contains("systemctl"));
+        assert!(hint(Unavailable::DaemonDown, "windows").contains("Docker Desktop"));
+    }
+
+    #[test]
+    fn test_unavailable_maps_to_docker_exit_code() {
+        let err = AirisError::DockerUnavailable(hint(Unavailable::DaemonDown, "macos"));
+        assert_eq!(err.exit_code(), 5);
+        assert!(err.to_string().starts_with("Docker is not available: "));
+    }
+}
@@ -0,0 +1,68 @@
+//! Typed failures surfaced at the CLI boundary
+//!
+//! Commands still return `anyhow::Result`; an `AirisError` anywhere in the
+//! error chain selects a dedicated process exit code so CI can branch on the
+//! kind of failure. Anything else exits with 1 (clap usage errors exit with 2).
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AirisError {
+    /// No manifest.toml in the current directory
+    #[error(
+        "manifest.toml not found. Create one (see docs/manifest.md) or ask Claude Code via /airis:init."
+    )]
+    ManifestMissing,
+
+    /// One or more validation or verification checks failed
+    #[error("{what} failed with {count} error(s)")]
+    ValidationFailed { what: &'static str, count: usize },
+
+    /// The docker CLI could not be executed
+    #[error("Docker is not available: {0}")]
+    DockerUnavailable(String),
+}
+
+impl AirisError {
+    /// Process exit code for this failure
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            AirisError::ManifestMissing => 3,
+            AirisError::ValidationFailed { .. } => 4,
+            AirisError::DockerUnavailable(_) => 5,
+        }
+    }
+}
+
+/// Exit code for an error returned by a command
+pub fn exit_code(err: &anyhow::Error) -> i32 {
+    err.chain()
+        .find_map(|cause| cause.downcast_ref::<AirisError>())
+        .map_or(1, AirisError::exit_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_exit_code_found_through_context() {
+        let err = Err::<(), _>(AirisError::ManifestMissing)
+            .context("Failed to load workspace")
+            .unwrap_err();
+        assert_eq!(exit_code(&err), 3);
+
+        let err = anyhow::Error::new(AirisError::ValidationFailed {
+            what: "Validation",
+            count: 2,
+        });
+        assert_eq!(exit_code(&err), 4);
+        assert_eq!(err.to_string(), "Validation failed with 2 error(s)");
+    }
+
+    #[test]
+    fn test_untyped_error_exits_with_one() {
+        assert_eq!(exit_code(&anyhow::anyhow!("boom")), 1);
+    }
+}
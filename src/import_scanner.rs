@@ -5,7 +5,7 @@
 //! eliminating the need to manually list deps in manifest.toml.
 
 use std::collections::BTreeSet;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
 use anyhow::{Context, Result};
@@ -39,7 +39,33 @@ pub struct ScannedDeps {
 /// - Relative imports (`./`, `../`) and path aliases (`@/`, `~/`, `#`) are skipped
 pub fn scan_imports(app_path: &Path, workspace_scope: &str) -> Result<ScannedDeps> {
     let mut deps = ScannedDeps::default();
+    for_each_source_file(app_path, |_, content| {
+        extract_packages(content, &IMPORT_RE, workspace_scope, &mut deps);
+    })?;
+    Ok(deps)
+}
+
+/// Scan all TypeScript/JavaScript files in a directory, keeping the package
+/// names imported by each file (for reporting which file caused a violation).
+///
+/// Files without package imports are omitted.
+pub fn scan_imports_by_file(app_path: &Path) -> Result<Vec<(PathBuf, BTreeSet<String>)>> {
+    let mut files = Vec::new();
+    for_each_source_file(app_path, |path, content| {
+        let packages: BTreeSet<String> = imported_packages(content, &IMPORT_RE)
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        if !packages.is_empty() {
+            files.push((path.to_path_buf(), packages));
+        }
+    })?;
+    Ok(files)
+}
 
+/// Walk `app_path` (skipping node_modules/dist/.next etc.) and call `f` with
+/// each readable source file and its content.
+fn for_each_source_file(app_path: &Path, mut f: impl FnMut(&Path, &str)) -> Result<()> {
     // Build ignore walker (respects .gitignore, skips common build dirs)
     let walker = WalkDir::new(app_path)
         .follow_links(false)
@@ -59,10 +85,10 @@ pub fn scan_imports(app_path: &Path, workspace_scope: &str) -> Result<ScannedDep
             Err(_) => continue, // skip unreadable files
         };
 
-        extract_packages(&content, &IMPORT_RE, workspace_scope, &mut deps);
+        f(path, &content);
     }
 
-    Ok(deps)
+    Ok(())
 }
 
 /// Scan a single file's content for imports (useful for testing).
@@ -73,7 +99,19 @@ pub fn scan_content(content: &str, workspace_scope: &str) -> ScannedDeps {
     deps
 }
 
-/// Extract package names from regex matches and classify them.
+/// Extract package names from regex matches and classify them by scope.
+fn extract_packages(content: &str, re: &Regex, workspace_scope: &str, deps: &mut ScannedDeps) {
+    for package_name in imported_packages(content, re) {
+        if package_name.starts_with(workspace_scope) {
+            deps.workspace.insert(package_name.to_string());
+        } else {
+            deps.external.insert(package_name.to_string());
+        }
+    }
+}
+
+/// Package names imported by `content`, excluding relative imports, path
+/// aliases and Node builtins.
 ///
 /// Processes content line-by-line to skip comments:
 /// - Line comments (`//`)
@@ -81,7 +119,8 @@ pub fn scan_content(content: &str, workspace_scope: &str) -> ScannedDeps {
 /// - JSDoc lines (`* ...`, `/** ...`)
 ///
 /// This prevents false positives from import examples in documentation.
-fn extract_packages(content: &str, re: &Regex, workspace_scope: &str, deps: &mut ScannedDeps) {
+fn imported_packages<'a>(content: &'a str, re: &Regex) -> Vec<&'a str> {
+    let mut packages = Vec::new();
     let mut in_block_comment = false;
 
     for line in content.lines() {
@@ -131,13 +170,11 @@ fn extract_packages(content: &str, re: &Regex, workspace_scope: &str, deps: &mut
                 continue;
             }
 
-            if package_name.starts_with(workspace_scope) {
-                deps.workspace.insert(package_name.to_string());
-            } else {
-                deps.external.insert(package_name.to_string());
-            }
+            packages.push(package_name);
         }
     }
+
+    packages
 }
 
 /// Extract the npm package name from an import specifier.
@@ -1,12 +1,40 @@
+//! airis-workspace as a library
+//!
+//! The `airis-workspace` binary is a thin clap wrapper over these modules, so
+//! tools can load the manifest, walk the workspace dependency graph, or run a
+//! command in-process instead of scraping CLI output.
+//!
+//! ```no_run
+//! use std::path::Path;
+//!
+//! use airis_workspace::{Manifest, build_dag, declared_package_manager, load_workspace_map};
+//!
+//! # fn main() -> anyhow::Result<()> {
+//! let root = Path::new(".");
+//! let manifest = Manifest::load("manifest.toml")?;
+//! // pnpm, npm or yarn: the declared package manager's lockfile wins
+//! let workspace = load_workspace_map(root, &declared_package_manager(root))?;
+//! let dag = build_dag(&workspace);
+//! let dependents = dag.get_dependents("libs/ui");
+//! # let _ = (manifest, dependents);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod airisignore;
 pub mod channel;
 pub mod cli;
 pub mod commands;
 pub mod conventions;
 pub mod dag;
+pub mod docker;
+pub mod error;
 pub mod executor;
 pub mod generators;
 pub mod import_scanner;
+pub mod lockfile;
 pub mod manifest;
+pub mod output;
 pub mod ownership;
 pub mod pnpm;
 pub mod preset;
@@ -17,3 +45,9 @@ pub mod templates;
 pub mod test_lock;
 pub mod version_resolver;
 pub mod workspace;
+
+pub use dag::{Dag, DagNode, build_dag};
+pub use error::AirisError;
+pub use lockfile::{declared_package_manager, load_workspace_map};
+pub use manifest::Manifest;
+pub use pnpm::{PnpmLock, WorkspacePackage, build_workspace_map};
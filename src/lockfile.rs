@@ -0,0 +1,416 @@
+//! Package-manager-agnostic workspace graph extraction
+//!
+//! Produces the same `workspace_map` (path → WorkspacePackage) that
+//! `pnpm::build_workspace_map` does, from npm, yarn (berry) or pnpm lockfiles,
+//! so DAG construction doesn't care which package manager the repo uses.
+
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::manifest::MANIFEST_FILE;
+use crate::pnpm::{PnpmLock, WorkspacePackage, build_workspace_map};
+
+/// A lockfile that can describe the workspace dependency graph
+pub trait WorkspaceLockfile {
+    /// Build the path → package map with intra-workspace edges
+    fn workspace_map(&self) -> HashMap<String, WorkspacePackage>;
+}
+
+impl WorkspaceLockfile for PnpmLock {
+    fn workspace_map(&self) -> HashMap<String, WorkspacePackage> {
+        build_workspace_map(self)
+    }
+}
+
+/// Lockfile names by package manager, in detection order
+const LOCKFILES: &[(&str, &str)] = &[
+    ("pnpm", "pnpm-lock.yaml"),
+    ("npm", "package-lock.json"),
+    ("yarn", "yarn.lock"),
+    ("bun", "bun.lock"),
+    ("bun", "bun.lockb"),
+];
+
+/// `[workspace].package_manager` from `root/manifest.toml`, read from the raw
+/// TOML so the lockfile lookup doesn't depend on the rest of the manifest
+/// loading. Empty when the file, the key or valid TOML is missing, which
+/// makes `load_workspace_map` detect the lockfile instead.
+pub fn declared_package_manager(root: &Path) -> String {
+    fs::read_to_string(root.join(MANIFEST_FILE))
+        .ok()
+        .and_then(|content| toml::from_str::<toml::Value>(&content).ok())
+        .and_then(|value| {
+            value
+                .get("workspace")?
+                .get("package_manager")?
+                .as_str()
+                .map(str::to_string)
+        })
+        .unwrap_or_default()
+}
+
+/// Load the workspace map from whichever lockfile the workspace uses.
+///
+/// `package_manager` is `manifest.workspace.package_manager` (e.g.
+/// `pnpm@10.0.0`); its lockfile is preferred. If that lockfile is missing,
+/// any other supported lockfile in `root` is used.
+pub fn load_workspace_map(
+    root: &Path,
+    package_manager: &str,
+) -> Result<HashMap<String, WorkspacePackage>> {
+    let pm = package_manager.split('@').next().unwrap_or_default();
+
+    let preferred = LOCKFILES.iter().filter(|(name, _)| *name == pm);
+    let others = LOCKFILES.iter().filter(|(name, _)| *name != pm);
+    let Some((_, file)) = preferred
+        .chain(others)
+        .find(|(_, file)| root.join(file).exists())
+    else {
+        bail!(
+            "No lockfile found (pnpm-lock.yaml, package-lock.json, yarn.lock). \
+             Run your package manager's install first or ensure you're in the workspace root."
+        );
+    };
+
+    let path = root.join(file);
+    let lock: Box<dyn WorkspaceLockfile> = match *file {
+        "pnpm-lock.yaml" => {
+            Box::new(PnpmLock::load(&path).context("Failed to parse pnpm-lock.yaml")?)
+        }
+        "package-lock.json" => Box::new(NpmLock::load(&path)?),
+        "yarn.lock" => Box::new(YarnLock::load(&path)?),
+        _ => bail!(
+            "{} is not supported yet. Use pnpm, npm, or yarn (berry) for dependency graphs.",
+            file
+        ),
+    };
+
+    Ok(lock.workspace_map())
+}
+
+// =============================================================================
+// npm (package-lock.json v2/v3)
+// =============================================================================
+
+/// package-lock.json (lockfileVersion 2/3, minimal for workspace resolution)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NpmLock {
+    pub lockfile_version: u32,
+    #[serde(default)]
+    pub packages: HashMap<String, NpmPackage>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NpmPackage {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub resolved: Option<String>,
+    #[serde(default)]
+    pub link: bool,
+    #[serde(default)]
+    pub dependencies: HashMap<String, String>,
+    #[serde(default)]
+    pub dev_dependencies: HashMap<String, String>,
+    #[serde(default)]
+    pub peer_dependencies: HashMap<String, String>,
+    #[serde(default)]
+    pub optional_dependencies: HashMap<String, String>,
+}
+
+impl NpmLock {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let lock: NpmLock =
+            serde_json::from_str(&content).context("Failed to parse package-lock.json")?;
+
+        if lock.lockfile_version < 2 {
+            bail!(
+                "Unsupported package-lock.json version: {}. Only v2/v3 are supported.",
+                lock.lockfile_version
+            );
+        }
+
+        Ok(lock)
+    }
+}
+
+impl WorkspaceLockfile for NpmLock {
+    fn workspace_map(&self) -> HashMap<String, WorkspacePackage> {
+        let is_workspace = |key: &str| !key.is_empty() && !key.contains("node_modules/");
+
+        // Package name → workspace path, from link entries and workspace entries
+        let mut by_name: HashMap<String, String> = HashMap::new();
+        for (key, pkg) in &self.packages {
+            if pkg.link
+                && let (Some((_, name)), Some(resolved)) =
+                    (key.rsplit_once("node_modules/"), &pkg.resolved)
+            {
+                by_name.insert(name.to_string(), resolved.clone());
+            } else if is_workspace(key)
+                && let Some(name) = &pkg.name
+            {
+                by_name.insert(name.clone(), key.clone());
+            }
+        }
+
+        self.packages
+            .iter()
+            .filter(|(key, _)| is_workspace(key))
+            .map(|(path, pkg)| {
+                let mut workspace_deps: Vec<String> = Vec::new();
+                let all = pkg
+                    .dependencies
+                    .keys()
+                    .chain(pkg.dev_dependencies.keys())
+                    .chain(pkg.peer_dependencies.keys())
+                    .chain(pkg.optional_dependencies.keys());
+                for dep in all {
+                    if let Some(dep_path) = by_name.get(dep)
+                        && dep_path != path
+                        && !workspace_deps.contains(dep_path)
+                    {
+                        workspace_deps.push(dep_path.clone());
+                    }
+                }
+
+                let name = pkg
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| path.rsplit('/').next().unwrap_or(path).to_string());
+                (
+                    path.clone(),
+                    WorkspacePackage {
+                        name,
+                        workspace_deps,
+                    },
+                )
+            })
+            .collect()
+    }
+}
+
+// =============================================================================
+// yarn (berry, yarn.lock v2+)
+// =============================================================================
+
+/// yarn.lock from Yarn 2+ (YAML). Yarn classic (v1) lockfiles don't record
+/// workspace packages and are rejected.
+#[derive(Debug)]
+pub struct YarnLock {
+    /// Entry key → entry
+    pub entries: HashMap<String, YarnEntry>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct YarnEntry {
+    #[serde(default)]
+    pub resolution: Option<String>,
+    #[serde(default)]
+    pub dependencies: HashMap<String, String>,
+    #[serde(default)]
+    pub peer_dependencies: HashMap<String, String>,
+}
+
+impl YarnLock {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&content)
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        if content.contains("# yarn lockfile v1") {
+            bail!(
+                "Yarn classic (v1) lockfiles don't record workspace packages. \
+                 Upgrade to Yarn 2+ or use pnpm/npm for dependency graphs."
+            );
+        }
+
+        let raw: HashMap<String, serde_yaml_ng::Value> =
+            serde_yaml_ng::from_str(content).context("Failed to parse yarn.lock")?;
+
+        let entries = raw
+            .into_iter()
+            .filter(|(key, _)| key != "__metadata")
+            .filter_map(|(key, value)| {
+                serde_yaml_ng::from_value::<YarnEntry>(value)
+                    .ok()
+                    .map(|e| (key, e))
+            })
+            .collect();
+
+        Ok(Self { entries })
+    }
+}
+
+impl WorkspaceLockfile for YarnLock {
+    fn workspace_map(&self) -> HashMap<String, WorkspacePackage> {
+        // "@scope/ui@workspace:libs/ui" → ("@scope/ui", "libs/ui")
+        let workspaces: Vec<(&str, &str, &YarnEntry)> = self
+            .entries
+            .values()
+            .filter_map(|entry| {
+                let resolution = entry.resolution.as_deref()?;
+                let (name, path) = resolution.rsplit_once("@workspace:")?;
+                (path != ".").then_some((name, path, entry))
+            })
+            .collect();
+
+        let by_name: HashMap<&str, &str> = workspaces
+            .iter()
+            .map(|(name, path, _)| (*name, *path))
+            .collect();
+
+        workspaces
+            .iter()
+            .map(|(name, path, entry)| {
+                let mut workspace_deps: Vec<String> = Vec::new();
+                for (dep, range) in entry.dependencies.iter().chain(&entry.peer_dependencies) {
+                    if !range.starts_with("workspace:") {
+                        continue;
+                    }
+                    if let Some(dep_path) = by_name.get(dep.as_str())
+                        && !workspace_deps.iter().any(|d| d == dep_path)
+                    {
+                        workspace_deps.push(dep_path.to_string());
+                    }
+                }
+                (
+                    path.to_string(),
+                    WorkspacePackage {
+                        name: name.to_string(),
+                        workspace_deps,
+                    },
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(mut v: Vec<String>) -> Vec<String> {
+        v.sort();
+        v
+    }
+
+    #[test]
+    fn test_npm_workspace_map() {
+        let json = r#"{
+  "name": "root",
+  "lockfileVersion": 3,
+  "packages": {
+    "": { "workspaces": ["apps/*", "libs/*"] },
+    "apps/web": {
+      "name": "web",
+      "dependencies": { "@scope/ui": "*", "react": "^19.0.0" },
+      "devDependencies": { "@scope/config": "*" }
+    },
+    "libs/ui": { "name": "@scope/ui", "dependencies": { "@scope/config": "*" } },
+    "libs/config": { "name": "@scope/config" },
+    "node_modules/@scope/ui": { "resolved": "libs/ui", "link": true },
+    "node_modules/@scope/config": { "resolved": "libs/config", "link": true },
+    "node_modules/react": { "version": "19.0.0" }
+  }
+}"#;
+        let lock: NpmLock = serde_json::from_str(json).unwrap();
+        let map = lock.workspace_map();
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(
+            sorted(map["apps/web"].workspace_deps.clone()),
+            vec!["libs/config", "libs/ui"]
+        );
+        assert_eq!(map["libs/ui"].workspace_deps, vec!["libs/config"]);
+        assert_eq!(map["libs/ui"].name, "@scope/ui");
+    }
+
+    #[test]
+    fn test_yarn_berry_workspace_map() {
+        let lock = YarnLock::parse(
+            r#"
+__metadata:
+  version: 8
+  cacheKey: 10
+
+"@scope/config@workspace:^, @scope/config@workspace:libs/config":
+  version: 0.0.0-use.local
+  resolution: "@scope/config@workspace:libs/config"
+  languageName: unknown
+  linkType: soft
+
+"@scope/ui@workspace:libs/ui":
+  version: 0.0.0-use.local
+  resolution: "@scope/ui@workspace:libs/ui"
+  dependencies:
+    "@scope/config": "workspace:^"
+    react: "npm:^19.0.0"
+  languageName: unknown
+  linkType: soft
+
+"root@workspace:.":
+  version: 0.0.0-use.local
+  resolution: "root@workspace:."
+  languageName: unknown
+  linkType: soft
+"#,
+        )
+        .unwrap();
+        let map = lock.workspace_map();
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["libs/ui"].workspace_deps, vec!["libs/config"]);
+        assert!(map["libs/config"].workspace_deps.is_empty());
+    }
+
+    #[test]
+    fn test_yarn_classic_is_rejected() {
+        let err =
+            YarnLock::parse("# THIS IS AN AUTOGENERATED FILE.\n# yarn lockfile v1\n").unwrap_err();
+        assert!(err.to_string().contains("Yarn classic"));
+    }
+
+    #[test]
+    fn test_load_workspace_map_falls_back_to_existing_lockfile() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("package-lock.json"),
+            r#"{"lockfileVersion": 3, "packages": {"": {}, "libs/ui": {"name": "ui"}}}"#,
+        )
+        .unwrap();
+
+        // Manifest says pnpm, but only an npm lockfile exists
+        let map = load_workspace_map(dir.path(), "pnpm@10.0.0").unwrap();
+        assert!(map.contains_key("libs/ui"));
+
+        let empty = tempfile::tempdir().unwrap();
+        assert!(load_workspace_map(empty.path(), "npm@10").is_err());
+    }
+
+    #[test]
+    fn test_declared_package_manager_reads_raw_toml() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(declared_package_manager(dir.path()), "");
+
+        // Sections Manifest::load would reject don't matter here
+        std::fs::write(
+            dir.path().join(MANIFEST_FILE),
+            "[workspace]\npackage_manager = \"npm@10.8.0\"\n\n[docker]\nmode = \"bogus\"\n",
+        )
+        .unwrap();
+        assert_eq!(declared_package_manager(dir.path()), "npm@10.8.0");
+
+        std::fs::write(dir.path().join(MANIFEST_FILE), "[workspace\n").unwrap();
+        assert_eq!(declared_package_manager(dir.path()), "");
+    }
+}
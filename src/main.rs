@@ -1,6 +1,7 @@
 use anyhow::Result;
-use clap::{CommandFactory, Parser};
+use clap::{CommandFactory, FromArgMatches};
 use colored::Colorize;
+use std::io::IsTerminal;
 
 use airis_workspace::cli::{
     ClaudeCommands, Cli, Commands, DepsCommands, DocsCommands, GenerateCommands, ManifestCommands,
@@ -33,7 +34,7 @@ fn main() {
 
     if let Err(e) = result {
         eprintln!("{}: {:?}", "Error".red().bold(), e);
-        std::process::exit(1);
+        std::process::exit(airis_workspace::error::exit_code(&e));
     }
 }
 
@@ -41,7 +42,26 @@ fn run_main() -> Result<()> {
     // Setup miette for fancy errors
     miette::set_panic_hook();
 
-    let cli = Cli::parse();
+    // Extensions are only listed in help, so skip the PATH scan otherwise
+    let help_requested =
+        std::env::args().any(|arg| matches!(arg.as_str(), "-h" | "--help" | "help"));
+    let cli_command = if help_requested {
+        commands::extension::with_extensions_help(Cli::command())
+    } else {
+        Cli::command()
+    };
+    let matches = cli_command.get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+
+    if !use_color(
+        cli.no_color,
+        std::env::var_os("NO_COLOR"),
+        std::env::var_os("CLICOLOR_FORCE"),
+        std::io::stdout().is_terminal(),
+    ) {
+        colored::control::set_override(false);
+    }
+    airis_workspace::output::set_quiet(cli.quiet);
 
     // Handle version flag
     if cli.version {
@@ -51,13 +71,31 @@ fn run_main() -> Result<()> {
 
     // Require a command if not printing version
     let command = cli.command.unwrap_or_else(|| {
-        Cli::command().print_help().unwrap();
+        commands::extension::with_extensions_help(Cli::command())
+            .print_help()
+            .unwrap();
         std::process::exit(0);
     });
 
     dispatch(command)
 }
 
+/// Whether to emit ANSI colors.
+///
+/// `--no-color` and a non-empty `NO_COLOR` always disable colors; otherwise
+/// they're on for terminals, or when `CLICOLOR_FORCE` is set (non-empty, not "0").
+fn use_color(
+    no_color_flag: bool,
+    no_color_env: Option<std::ffi::OsString>,
+    force_env: Option<std::ffi::OsString>,
+    stdout_is_tty: bool,
+) -> bool {
+    if no_color_flag || no_color_env.is_some_and(|v| !v.is_empty()) {
+        return false;
+    }
+    stdout_is_tty || force_env.is_some_and(|v| !v.is_empty() && v != "0")
+}
+
 /// Dispatch a parsed CLI command to the appropriate handler.
 fn dispatch(command: Commands) -> Result<()> {
     match command {
@@ -68,6 +106,11 @@ fn dispatch(command: Commands) -> Result<()> {
                 ManifestCommands::DevApps => ManifestAction::DevApps,
                 ManifestCommands::Rule { name } => ManifestAction::Rule { name },
                 ManifestCommands::Json => ManifestAction::Json,
+                ManifestCommands::ImportScripts { raw } => ManifestAction::ImportScripts { raw },
+                ManifestCommands::Get { path, default } => ManifestAction::Get { path, default },
+                ManifestCommands::Set { path, value, json } => {
+                    ManifestAction::Set { path, value, json }
+                }
             };
 
             manifest_cmd::run(manifest_action)?;
@@ -100,7 +143,7 @@ fn dispatch(command: Commands) -> Result<()> {
 
             validate_cmd::run(validate_action, json)?;
         }
-        Commands::Verify => commands::verify::run()?,
+        Commands::Verify { live } => commands::verify::run(live)?,
         Commands::Doctor {
             fix,
             truth,
@@ -124,40 +167,74 @@ fn dispatch(command: Commands) -> Result<()> {
             commands::clean::run(actual_dry_run, purge, allow_anywhere)?;
         }
         Commands::New { template } => match template {
-            NewCommands::Api { name, runtime } => {
-                commands::new_cmd::run_with_runtime("api", &name, &runtime)?;
+            NewCommands::Api {
+                name,
+                runtime,
+                no_register,
+            } => {
+                commands::new_cmd::run_with_runtime("api", &name, &runtime, !no_register)?;
             }
-            NewCommands::Web { name, runtime } => {
-                commands::new_cmd::run_with_runtime("web", &name, &runtime)?;
+            NewCommands::Web {
+                name,
+                runtime,
+                no_register,
+            } => {
+                commands::new_cmd::run_with_runtime("web", &name, &runtime, !no_register)?;
             }
-            NewCommands::Lib { name, runtime } => {
-                commands::new_cmd::run_with_runtime("lib", &name, &runtime)?;
+            NewCommands::Lib {
+                name,
+                runtime,
+                no_register,
+            } => {
+                commands::new_cmd::run_with_runtime("lib", &name, &runtime, !no_register)?;
             }
             NewCommands::Edge { name } => {
-                commands::new_cmd::run_with_runtime("edge", &name, "deno")?;
+                commands::new_cmd::run_with_runtime("edge", &name, "deno", false)?;
             }
             NewCommands::SupabaseTrigger { name } => {
-                commands::new_cmd::run_with_runtime("supabase-trigger", &name, "plpgsql")?;
+                commands::new_cmd::run_with_runtime("supabase-trigger", &name, "plpgsql", false)?;
             }
             NewCommands::SupabaseRealtime { name } => {
-                commands::new_cmd::run_with_runtime("supabase-realtime", &name, "deno")?;
+                commands::new_cmd::run_with_runtime("supabase-realtime", &name, "deno", false)?;
             }
         },
         Commands::Gen {
             dry_run,
             force,
+            overwrite_user_files,
             migrate,
+            only,
+            except,
         } => {
-            commands::generate::run(dry_run, force, migrate)?;
+            let filter = commands::generate::GenFilter::new(only, except)?;
+            commands::generate::run(dry_run, force, overwrite_user_files, migrate, &filter)?;
         }
         Commands::Generate { action } => match action {
+            GenerateCommands::DepsConfig { target } => {
+                commands::generate_deps_config::run(&target)?;
+            }
+            GenerateCommands::Openapi { input, output } => {
+                commands::generate_openapi::run(&input, &output)?;
+            }
             GenerateCommands::Types {
+                engine,
                 host,
                 port,
                 database,
+                user,
+                password,
                 output,
+                zod,
+                watch,
             } => {
-                commands::generate_types::run(&host, &port, &database, &output)?;
+                let conn = commands::generate_types::Connection::resolve(
+                    &engine, host, port, database, user, password,
+                )?;
+                if watch {
+                    commands::generate_types::watch(&conn, &output, zod)?;
+                } else {
+                    commands::generate_types::run(&conn, &output, zod)?;
+                }
             }
         },
         Commands::BumpVersion {
@@ -165,6 +242,8 @@ fn dispatch(command: Commands) -> Result<()> {
             minor,
             patch,
             auto: _,
+            tag,
+            push,
         } => {
             use commands::bump_version::{self, BumpMode};
 
@@ -179,7 +258,7 @@ fn dispatch(command: Commands) -> Result<()> {
                 BumpMode::Auto
             };
 
-            bump_version::run(mode)?;
+            bump_version::run(mode, tag, push)?;
         }
         Commands::Policy { action } => match action {
             PolicyCommands::Init => commands::policy::init()?,
@@ -194,6 +273,10 @@ fn dispatch(command: Commands) -> Result<()> {
             DepsCommands::Tree => commands::deps::tree()?,
             DepsCommands::Json => commands::deps::json()?,
             DepsCommands::Show { package } => commands::deps::show(&package)?,
+            DepsCommands::Graph { format } => commands::deps::graph(&format)?,
+            DepsCommands::Dependents { package, json } => {
+                commands::deps::dependents(&package, json)?
+            }
             DepsCommands::Check => commands::deps::check()?,
         },
         Commands::Diff { json, stat } => {
@@ -223,7 +306,31 @@ fn dispatch(command: Commands) -> Result<()> {
         Commands::Mcp => {
             commands::mcp::run()?;
         }
+        Commands::External(args) => {
+            commands::extension::run(&args)?;
+        }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_use_color() {
+        let env = |v: &str| Some(std::ffi::OsString::from(v));
+
+        assert!(use_color(false, None, None, true));
+        assert!(!use_color(true, None, None, true));
+        assert!(!use_color(false, env("1"), None, true));
+        // An empty NO_COLOR doesn't count
+        assert!(use_color(false, env(""), None, true));
+        // Piped output is plain unless forced
+        assert!(!use_color(false, None, None, false));
+        assert!(use_color(false, None, env("1"), false));
+        assert!(!use_color(false, None, env("0"), false));
+        assert!(!use_color(false, env("1"), env("1"), true));
+    }
+}
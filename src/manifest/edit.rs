@@ -0,0 +1,463 @@
+//! In-place edits of manifest.toml text
+//!
+//! Edits go through a `toml_edit` document, so comments, ordering and
+//! formatting of everything that isn't touched survive.
+
+use anyhow::{Context, Result, bail};
+use toml_edit::{ArrayOfTables, DocumentMut, InlineTable, Item, Value};
+
+/// Top-level keys `Manifest` understands. `airis manifest set` refuses others
+/// so a typo can't create a junk section.
+pub const MANIFEST_SECTIONS: &[&str] = &[
+    "version",
+    "project",
+    "workspace",
+    "workspaces",
+    "dev",
+    "apps",
+    "libs",
+    "docker",
+    "just",
+    "service",
+    "rule",
+    "packages",
+    "app",
+    "orchestration",
+    "hooks",
+    "commands",
+    "remap",
+    "versioning",
+    "docs",
+    "ai",
+    "ci",
+    "templates",
+    "runtimes",
+    "env",
+    "secrets",
+    "typescript",
+    "stack",
+    "dep_group",
+    "env_group",
+    "profile",
+    "preset",
+    "external",
+    "root",
+    "overrides",
+    "mcp",
+    "testing",
+    "policy",
+    "build",
+    "verify",
+    "arch",
+    "ownership",
+];
+
+/// Split a dotted path (`ci.auto_merge.enabled`, `app.0.name`) into segments
+pub fn split_toml_path(path: &str) -> Result<Vec<String>> {
+    let segments: Vec<String> = path.split('.').map(str::to_string).collect();
+    if segments.iter().any(|s| s.is_empty()) {
+        bail!("Invalid path `{}`: empty segment", path);
+    }
+    Ok(segments)
+}
+
+/// Set `path` to `value` in manifest text and return the updated text.
+///
+/// An existing value is replaced in place (its trailing comment is kept).
+/// Missing keys are appended to their table, and missing tables are created.
+pub fn set_toml_value(content: &str, path: &str, value: toml::Value) -> Result<String> {
+    set_toml_segments(content, &split_toml_path(path)?, value)
+}
+
+/// [`set_toml_value`] for a path given as segments, for keys that may
+/// themselves contain dots
+pub fn set_toml_segments(content: &str, segments: &[String], value: toml::Value) -> Result<String> {
+    let mut doc = parse(content)?;
+    set_in_doc(&mut doc, segments, Some(&value))?;
+    Ok(doc.to_string())
+}
+
+/// Rewrite `content` so the values that differ between `before` and `after`
+/// take their `after` form, leaving everything else untouched.
+///
+/// Tables, and arrays that kept or grew their length, are compared element
+/// by element (new elements are appended); any other changed value is
+/// rewritten as one entry. Keys missing from `after` are removed.
+pub fn update_toml_text(
+    content: &str,
+    before: &toml::Value,
+    after: &toml::Value,
+) -> Result<String> {
+    let mut changes = Vec::new();
+    collect_changes(before, after, &mut Vec::new(), &mut changes);
+
+    let mut doc = parse(content)?;
+    for (segments, value) in changes {
+        set_in_doc(&mut doc, &segments, value)?;
+    }
+    Ok(doc.to_string())
+}
+
+fn parse(content: &str) -> Result<DocumentMut> {
+    content
+        .parse::<DocumentMut>()
+        .context("Failed to parse manifest.toml")
+}
+
+/// Changed paths and their new value (`None` = removed)
+type Changes<'a> = Vec<(Vec<String>, Option<&'a toml::Value>)>;
+
+fn collect_changes<'a>(
+    before: &toml::Value,
+    after: &'a toml::Value,
+    prefix: &mut Vec<String>,
+    changes: &mut Changes<'a>,
+) {
+    match (before, after) {
+        (toml::Value::Table(old), toml::Value::Table(new)) => {
+            for key in old.keys().filter(|k| !new.contains_key(*k)) {
+                prefix.push(key.clone());
+                changes.push((prefix.clone(), None));
+                prefix.pop();
+            }
+            for (key, value) in new {
+                prefix.push(key.clone());
+                match old.get(key) {
+                    Some(old) => collect_changes(old, value, prefix, changes),
+                    None => changes.push((prefix.clone(), Some(value))),
+                }
+                prefix.pop();
+            }
+        }
+        (toml::Value::Array(old), toml::Value::Array(new)) if old.len() <= new.len() => {
+            for (i, new) in new.iter().enumerate() {
+                prefix.push(i.to_string());
+                match old.get(i) {
+                    Some(old) => collect_changes(old, new, prefix, changes),
+                    None => changes.push((prefix.clone(), Some(new))),
+                }
+                prefix.pop();
+            }
+        }
+        _ if before != after => changes.push((prefix.clone(), Some(after))),
+        _ => {}
+    }
+}
+
+/// Set (or with `None`, remove) the value at `segments`
+fn set_in_doc(
+    doc: &mut DocumentMut,
+    segments: &[String],
+    value: Option<&toml::Value>,
+) -> Result<()> {
+    let path = segments.join(".");
+    let (last, parents) = segments
+        .split_last()
+        .context("Cannot replace the whole document")?;
+
+    let mut current = doc.as_item_mut();
+    for segment in parents {
+        current = child_mut(current, segment, value.is_some())
+            .with_context(|| format!("Cannot set `{}`", path))?;
+    }
+
+    let Some(value) = value else {
+        let removed = match current {
+            Item::Table(table) => table.remove(last).is_some(),
+            Item::Value(Value::InlineTable(table)) => table.remove(last).is_some(),
+            _ => false,
+        };
+        if !removed {
+            bail!("Cannot remove `{}`", path);
+        }
+        return Ok(());
+    };
+
+    let slot = child_mut(current, last, true).with_context(|| format!("Cannot set `{}`", path))?;
+    *slot = to_item(value, slot).with_context(|| format!("Cannot set `{}`", path))?;
+    Ok(())
+}
+
+/// Step into `segment` of a table or array; with `create`, missing table
+/// keys are added (as tables, or as inline tables inside inline values) and
+/// the index one past the end of an array appends a new element.
+fn child_mut<'a>(item: &'a mut Item, segment: &str, create: bool) -> Result<&'a mut Item> {
+    let len = match item {
+        Item::ArrayOfTables(tables) => Some(tables.len()),
+        Item::Value(Value::Array(items)) => Some(items.len()),
+        _ => None,
+    };
+    if let Some(len) = len {
+        let index: usize = segment
+            .parse()
+            .with_context(|| format!("`{}` is not an array index", segment))?;
+        if create && index == len {
+            // Placeholder, replaced by `to_item` (which keeps its decor)
+            match item {
+                Item::ArrayOfTables(tables) => tables.push(toml_edit::Table::new()),
+                Item::Value(Value::Array(items)) => items.push(false),
+                _ => {}
+            }
+        }
+        return item
+            .get_mut(index)
+            .with_context(|| format!("index {} out of range (array has {})", index, len));
+    }
+
+    let inline = match item {
+        Item::Table(_) => false,
+        Item::Value(Value::InlineTable(_)) => true,
+        _ => bail!("parent of `{}` is not a table", segment),
+    };
+    if !create && item.get(segment).is_none() {
+        bail!("`{}` does not exist", segment);
+    }
+    let child = item
+        .get_mut(segment)
+        .with_context(|| format!("parent of `{}` is not a table", segment))?;
+    if child.is_none() {
+        *child = if inline {
+            Item::Value(Value::InlineTable(InlineTable::new()))
+        } else {
+            let mut table = toml_edit::Table::new();
+            table.set_implicit(true);
+            Item::Table(table)
+        };
+    }
+    Ok(child)
+}
+
+/// Convert `value` for the slot it replaces: arrays of tables and tables
+/// keep their block form, and a replaced value keeps its comment/spacing.
+fn to_item(value: &toml::Value, slot: &Item) -> Result<Item> {
+    match (value, slot) {
+        (toml::Value::Table(table), Item::Table(_)) => Ok(Item::Table(to_table(table)?)),
+        (toml::Value::Array(items), slot)
+            if is_new_or_tables(slot)
+                && !items.is_empty()
+                && items.iter().all(toml::Value::is_table) =>
+        {
+            let mut tables = ArrayOfTables::new();
+            for item in items {
+                tables.push(to_table(item.as_table().context("not a table")?)?);
+            }
+            Ok(Item::ArrayOfTables(tables))
+        }
+        _ => {
+            let mut new: Value = inline_toml(value)
+                .parse()
+                .context("Failed to render value")?;
+            if let Some(old) = slot.as_value() {
+                *new.decor_mut() = old.decor().clone();
+            }
+            Ok(Item::Value(new))
+        }
+    }
+}
+
+/// An array-of-tables slot, or a missing key (which `child_mut` leaves as an
+/// empty implicit table)
+fn is_new_or_tables(slot: &Item) -> bool {
+    match slot {
+        Item::ArrayOfTables(_) | Item::None => true,
+        Item::Table(table) => table.is_implicit() && table.is_empty(),
+        _ => false,
+    }
+}
+
+fn to_table(table: &toml::Table) -> Result<toml_edit::Table> {
+    let doc = parse(&toml::to_string(table).context("Failed to render table")?)?;
+    Ok(doc.as_table().clone())
+}
+
+/// Quote a key unless it is a valid bare TOML key
+pub fn toml_key(name: &str) -> String {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        name.to_string()
+    } else {
+        toml::Value::String(name.to_string()).to_string()
+    }
+}
+
+/// Render a value on one line (arrays and tables inline)
+pub fn inline_toml(value: &toml::Value) -> String {
+    match value {
+        toml::Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(inline_toml).collect();
+            format!("[{}]", items.join(", "))
+        }
+        toml::Value::Table(table) if table.is_empty() => "{}".to_string(),
+        toml::Value::Table(table) => {
+            let entries: Vec<String> = table
+                .iter()
+                .map(|(k, v)| format!("{} = {}", toml_key(k), inline_toml(v)))
+                .collect();
+            format!("{{ {} }}", entries.join(", "))
+        }
+        scalar => scalar.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"# Workspace manifest
+version = 1
+
+[workspace]
+name = "demo" # shown in prompts
+
+[ci]
+repository = "acme/demo"
+
+[ci.auto_merge]
+enabled = false
+
+[[app]]
+name = "web"
+ports = [
+  3000,
+]
+
+[[app]]
+name = "api"
+
+# Rules
+[rule.verify]
+commands = []
+"#;
+
+    fn set(path: &str, value: toml::Value) -> String {
+        set_toml_value(MANIFEST, path, value).unwrap()
+    }
+
+    #[test]
+    fn test_set_replaces_leaf_and_keeps_comments() {
+        let updated = set("workspace.name", toml::Value::String("acme".into()));
+        assert!(updated.contains("name = \"acme\" # shown in prompts\n"));
+        assert!(updated.starts_with("# Workspace manifest\n"));
+
+        let updated = set("ci.auto_merge.enabled", toml::Value::Boolean(true));
+        assert!(updated.contains("[ci.auto_merge]\nenabled = true\n"));
+    }
+
+    #[test]
+    fn test_set_array_of_tables_and_elements() {
+        let updated = set("app.1.name", toml::Value::String("backend".into()));
+        assert!(updated.contains("[[app]]\nname = \"backend\"\n"));
+        assert!(updated.contains("[[app]]\nname = \"web\"\n"));
+
+        let updated = set("app.0.ports.0", toml::Value::Integer(3100));
+        assert!(updated.contains("ports = [\n  3100,\n]\n\n[[app]]"));
+    }
+
+    #[test]
+    fn test_set_inserts_missing_key_and_table() {
+        let updated = set("ci.default_branch", toml::Value::String("main".into()));
+        assert!(
+            updated.contains(
+                "repository = \"acme/demo\"\ndefault_branch = \"main\"\n\n[ci.auto_merge]"
+            )
+        );
+
+        let updated = set("docker.compose", toml::Value::String("compose.yml".into()));
+        assert!(updated.ends_with("commands = []\n\n[docker]\ncompose = \"compose.yml\"\n"));
+    }
+
+    #[test]
+    fn test_set_rejects_out_of_range_index() {
+        let err =
+            set_toml_value(MANIFEST, "app.5.name", toml::Value::String("x".into())).unwrap_err();
+        assert!(format!("{:#}", err).contains("out of range"));
+    }
+
+    #[test]
+    fn test_update_toml_text_touches_only_changed_values() {
+        let before: toml::Value = toml::from_str(MANIFEST).unwrap();
+        let mut after = before.clone();
+        after["workspace"]["name"] = toml::Value::String("acme".into());
+        after["rule"]["verify"]["commands"] = toml::Value::Array(vec!["pnpm lint".into()]);
+
+        let updated = update_toml_text(MANIFEST, &before, &after).unwrap();
+        let changed: Vec<(&str, &str)> = MANIFEST
+            .lines()
+            .zip(updated.lines())
+            .filter(|(a, b)| a != b)
+            .collect();
+        assert_eq!(
+            changed,
+            vec![
+                (
+                    "name = \"demo\" # shown in prompts",
+                    "name = \"acme\" # shown in prompts"
+                ),
+                ("commands = []", "commands = [\"pnpm lint\"]"),
+            ]
+        );
+
+        let mut removed = before.clone();
+        removed.as_table_mut().unwrap().remove("ci");
+        let updated = update_toml_text(MANIFEST, &before, &removed).unwrap();
+        assert!(!updated.contains("[ci"));
+        assert!(updated.contains("[workspace]\nname = \"demo\" # shown in prompts\n"));
+    }
+
+    #[test]
+    fn test_update_toml_text_keeps_array_of_tables() {
+        let before: toml::Value = toml::from_str(MANIFEST).unwrap();
+        let mut after = before.clone();
+        after["app"][1]["name"] = toml::Value::String("backend".into());
+        let updated = update_toml_text(MANIFEST, &before, &after).unwrap();
+        assert!(updated.contains("[[app]]\nname = \"backend\"\n"));
+
+        let mut grown = before.clone();
+        let mut worker = toml::Table::new();
+        worker.insert("name".into(), "worker".into());
+        grown["app"]
+            .as_array_mut()
+            .unwrap()
+            .push(worker.clone().into());
+        let updated = update_toml_text(MANIFEST, &before, &grown).unwrap();
+        assert_eq!(updated.matches("[[app]]").count(), 3);
+        assert!(updated.contains("name = \"worker\""));
+        assert!(updated.starts_with("# Workspace manifest\n"));
+        // Growing an array appends; existing entries keep their formatting
+        assert!(updated.contains("[[app]]\nname = \"web\"\nports = [\n  3000,\n]\n"));
+        assert!(updated.contains("[[app]]\nname = \"worker\"\n\n# Rules\n"));
+
+        let mut ports = before.clone();
+        ports["app"][0]["ports"]
+            .as_array_mut()
+            .unwrap()
+            .push(3001.into());
+        let updated = update_toml_text(MANIFEST, &before, &ports).unwrap();
+        assert!(updated.contains("ports = [\n  3000, 3001,\n]\n"));
+
+        // A new array of tables is written in block form
+        let mut added = before.clone();
+        added
+            .as_table_mut()
+            .unwrap()
+            .insert("service".into(), toml::Value::Array(vec![worker.into()]));
+        let updated = update_toml_text(MANIFEST, &before, &added).unwrap();
+        assert!(updated.ends_with("[[service]]\nname = \"worker\"\n"));
+    }
+
+    #[test]
+    fn test_manifest_sections_cover_schema() {
+        let manifest = crate::manifest::Manifest::default_with_project("demo");
+        let value: toml::Value = toml::from_str(&toml::to_string(&manifest).unwrap()).unwrap();
+        for key in value.as_table().unwrap().keys() {
+            assert!(
+                MANIFEST_SECTIONS.contains(&key.as_str()),
+                "{} missing from MANIFEST_SECTIONS",
+                key
+            );
+        }
+    }
+}
@@ -1,3 +1,4 @@
+mod edit;
 mod global_config;
 mod schema;
 pub(crate) mod validation;
@@ -5,6 +6,7 @@ pub(crate) mod validation;
 #[cfg(test)]
 mod tests;
 
+pub use edit::*;
 pub use global_config::*;
 pub use schema::*;
 
@@ -38,7 +40,7 @@ impl Manifest {
         let mut manifest: Manifest =
             toml::from_str(content).with_context(|| "Failed to parse manifest.toml")?;
 
-        manifest.migrate_testing_to_policy();
+        manifest.migrate_testing_to_policy(true);
         manifest.warn_runtime_image_overlap();
 
         if let Err(e) = manifest.validate() {
@@ -60,7 +62,7 @@ impl Manifest {
             toml::from_str(content).with_context(|| "Failed to parse manifest.toml")?;
 
         // [testing] → [policy.testing] migration fallback
-        manifest.migrate_testing_to_policy();
+        manifest.migrate_testing_to_policy(true);
         manifest.warn_runtime_image_overlap();
 
         manifest.validate()?;
@@ -100,15 +102,17 @@ impl Manifest {
         }
     }
 
-    /// Migrate top-level [testing] to [policy.testing] with deprecation warning.
-    fn migrate_testing_to_policy(&mut self) {
+    /// Migrate top-level [testing] to [policy.testing], warning when `warn` is set.
+    fn migrate_testing_to_policy(&mut self, warn: bool) {
         let has_top_level_testing = self.testing != TestingSection::default();
         let has_policy_testing = self.policy.testing != TestingSection::default();
 
         if has_top_level_testing && !has_policy_testing {
             self.policy.testing = self.testing.clone();
-            eprintln!("⚠️  [testing] is deprecated. Move to [policy.testing] in manifest.toml");
-        } else if has_top_level_testing && has_policy_testing {
+            if warn {
+                eprintln!("⚠️  [testing] is deprecated. Move to [policy.testing] in manifest.toml");
+            }
+        } else if warn && has_top_level_testing && has_policy_testing {
             eprintln!(
                 "⚠️  Both [testing] and [policy.testing] found. Using [policy.testing]. Remove [testing]."
             );
@@ -267,16 +271,50 @@ impl Manifest {
         !self.workspace.package_manager.is_empty()
     }
 
+    /// Whether host-side dependency installs are a mistake (`[docker] mode`)
+    pub fn is_docker_first(&self) -> bool {
+        match self.docker.mode {
+            Some(mode) => mode == DockerMode::DockerFirst,
+            None => self.docker.workspace.is_some(),
+        }
+    }
+
+    /// Write the manifest back to `path`.
+    ///
+    /// When the file already exists, only the values that differ from what it
+    /// loads to are rewritten, so comments and layout survive. If that isn't
+    /// possible the error is returned and the file is left untouched.
     pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-        let content = toml::to_string_pretty(self)
+        let rendered = toml::to_string_pretty(self)
             .with_context(|| "Failed to serialize manifest.toml contents")?;
 
+        let content = if path.as_ref().exists() {
+            let existing = fs::read_to_string(path.as_ref())
+                .with_context(|| format!("Failed to read {:?}", path.as_ref()))?;
+            self.update_in_place(&existing, &rendered)
+                .with_context(|| format!("Could not update {:?} in place", path.as_ref()))?
+        } else {
+            rendered
+        };
+
         fs::write(path.as_ref(), content)
             .with_context(|| format!("Failed to write {:?}", path.as_ref()))?;
 
         Ok(())
     }
 
+    /// Apply this manifest's changes relative to `existing` onto its text
+    fn update_in_place(&self, existing: &str, rendered: &str) -> Result<String> {
+        // Mirror the load pipeline so resolved defaults cancel out
+        let mut loaded: Manifest = toml::from_str(existing)?;
+        loaded.migrate_testing_to_policy(false);
+        loaded.resolve_conventions();
+
+        let before: toml::Value = toml::from_str(&toml::to_string_pretty(&loaded)?)?;
+        let after: toml::Value = toml::from_str(rendered)?;
+        update_toml_text(existing, &before, &after)
+    }
+
     /// Get the effective Node.js version.
     /// Priority: [workspace].node > [ci].node_version > extracted from [workspace].image > "22"
     #[allow(dead_code)]
@@ -470,6 +508,7 @@ impl Manifest {
                 compose: default_compose_file(),
                 service: String::new(),
                 routes: vec![],
+                mode: None,
             },
             just: None,
             service: IndexMap::new(),
@@ -515,6 +554,10 @@ impl Manifest {
             mcp: McpSection::default(),
             testing: TestingSection::default(),
             policy: PolicySection::default(),
+            build: BuildSection::default(),
+            verify: VerifySection::default(),
+            arch: ArchSection::default(),
+            ownership: OwnershipSection::default(),
         }
     }
 }
@@ -118,6 +118,109 @@ pub struct Manifest {
     /// Code governance policy
     #[serde(default)]
     pub policy: PolicySection,
+    /// Build settings (base image mirrors)
+    #[serde(default, skip_serializing_if = "BuildSection::is_empty")]
+    pub build: BuildSection,
+    /// Project-specific preflight checks for airis verify
+    #[serde(default, skip_serializing_if = "VerifySection::is_empty")]
+    pub verify: VerifySection,
+    /// Dependency architecture rules for airis validate arch
+    #[serde(default, skip_serializing_if = "ArchSection::is_empty")]
+    pub arch: ArchSection,
+    /// File ownership overrides for generated-file protection
+    #[serde(default, skip_serializing_if = "OwnershipSection::is_empty")]
+    pub ownership: OwnershipSection,
+}
+
+/// Build configuration: `[build]`
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct BuildSection {
+    /// Base image prefix per runtime family (`node`, `rust`, `python`, `bun`,
+    /// `deno`), e.g. `node = "registry.corp/mirror/node"`. A prefix ending in
+    /// `/` is prepended to the full default image instead.
+    #[serde(default)]
+    pub images: IndexMap<String, String>,
+}
+
+impl BuildSection {
+    fn is_empty(&self) -> bool {
+        self.images.is_empty()
+    }
+}
+
+/// Verify configuration: `[verify]`
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct VerifySection {
+    /// Named checks run by `airis verify` after the built-in checks, e.g.
+    /// `[verify.checks.auth]`
+    #[serde(default)]
+    pub checks: IndexMap<String, VerifyCheck>,
+}
+
+impl VerifySection {
+    fn is_empty(&self) -> bool {
+        self.checks.is_empty()
+    }
+}
+
+/// Dependency architecture configuration: `[arch]`
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct ArchSection {
+    /// Permitted app → app edges, keyed by the importing app, e.g.
+    /// `"apps/web" = ["apps/admin"]`. Package names work on either side.
+    #[serde(default)]
+    pub allow: IndexMap<String, Vec<String>>,
+    /// Ordered tiers, highest first (`[[arch.layers]]`). A package may only
+    /// depend on its own tier or lower ones. Replaces the apps → libs rule.
+    #[serde(default)]
+    pub layers: Vec<ArchLayer>,
+}
+
+impl ArchSection {
+    fn is_empty(&self) -> bool {
+        self.allow.is_empty() && self.layers.is_empty()
+    }
+}
+
+/// File ownership overrides: `[ownership]`
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct OwnershipSection {
+    /// Globs (relative to the workspace root) airis may back up and overwrite
+    #[serde(default)]
+    pub tool_owned: Vec<String>,
+    /// Globs airis must never overwrite or delete. Wins over `tool_owned`.
+    #[serde(default)]
+    pub user_owned: Vec<String>,
+}
+
+impl OwnershipSection {
+    fn is_empty(&self) -> bool {
+        self.tool_owned.is_empty() && self.user_owned.is_empty()
+    }
+}
+
+/// A single tier: `{ name = "domain", paths = ["libs/domain*"] }`
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ArchLayer {
+    pub name: String,
+    /// Glob patterns matched against workspace package paths
+    pub paths: Vec<String>,
+    /// Forbid dependencies between packages of this same tier (e.g. apps)
+    #[serde(default)]
+    pub isolated: bool,
+}
+
+/// A single custom check: `{ command = "...", expect_success = true, message = "..." }`
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct VerifyCheck {
+    /// Shell command run from the workspace root on the host
+    pub command: String,
+    /// Whether the check passes on exit 0 (default) or on a non-zero exit
+    #[serde(default = "default_true")]
+    pub expect_success: bool,
+    /// Shown when the check fails
+    #[serde(default)]
+    pub message: Option<String>,
 }
 
 /// Project metadata - Source of Truth for Cargo.toml, Homebrew formula, etc.
@@ -627,6 +730,19 @@ pub struct DockerSection {
     /// Command routing rules (glob pattern → service/workdir)
     #[serde(default)]
     pub routes: Vec<DockerRoute>,
+    /// How strictly dependencies must stay inside containers. Unset means
+    /// docker-first when `[docker.workspace]` is configured, hybrid otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<DockerMode>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum DockerMode {
+    /// Dependencies and build outputs live only in container volumes
+    DockerFirst,
+    /// Host installs are allowed alongside containers
+    Hybrid,
 }
 
 pub(crate) fn default_compose_file() -> String {
@@ -919,7 +1035,7 @@ pub struct ProjectDefinition {
     #[serde(default)]
     pub files: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub framework: Option<String>, // "react-vite" | "nextjs" | "node" | "rust" | "python"
+    pub framework: Option<String>, // "react-vite" | "nextjs" | "sveltekit" | "remix" | "node" | "rust" | "python"
     /// Python version (e.g., "3.12")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub python: Option<String>,
@@ -1232,6 +1348,9 @@ pub struct OrchestrationDev {
     /// their per-service `restart` value (which is for deploy).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub restart: Option<String>,
+    /// Compose profiles to activate (e.g., ["full"]); each becomes `--profile <name>`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub profiles: Vec<String>,
 }
 
 /// Version management configuration
@@ -1267,7 +1386,7 @@ fn default_version_source() -> String {
 pub enum VersioningStrategy {
     /// Manual version bumps only
     Manual,
-    /// Auto-increment minor version on every commit
+    /// Detect the bump from commits since the last tag (patch fallback)
     Auto,
     /// Use Conventional Commits to determine bump type
     ConventionalCommits,
@@ -1339,6 +1458,9 @@ pub enum DocsVendor {
     Codex,
     Claude,
     Gemini,
+    Windsurf,
+    Aider,
+    Continue,
 }
 
 /// AI tool configuration — Single Source of Truth for AI rules.
@@ -1735,6 +1857,10 @@ pub struct RootSection {
 /// Templates configuration for airis new
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct TemplatesSection {
+    /// Directory of project template overrides, one subdirectory per
+    /// `<category>-<runtime>` (e.g. `api-hono`). `AIRIS_TEMPLATES_DIR` wins.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dir: Option<String>,
     /// API templates (e.g., hono, fastapi, rust-axum)
     #[serde(default)]
     pub api: IndexMap<String, TemplateConfig>,
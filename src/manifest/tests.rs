@@ -947,3 +947,73 @@ test = "vitest run"
         Some("vitest run")
     );
 }
+
+#[test]
+fn test_verify_checks_parse_with_defaults() {
+    let toml = r#"
+version = 1
+[project]
+id = "test"
+
+[verify.checks.auth]
+command = "grep -q 'enabled = true' supabase/config.toml"
+message = "Enable [auth] in supabase/config.toml"
+
+[verify.checks.no-env]
+command = "test -f .env"
+expect_success = false
+"#;
+    let manifest = load_from_str(toml).unwrap();
+    let checks = &manifest.verify.checks;
+    assert_eq!(checks.len(), 2);
+    assert!(checks["auth"].expect_success);
+    assert_eq!(
+        checks["auth"].message.as_deref(),
+        Some("Enable [auth] in supabase/config.toml")
+    );
+    assert!(!checks["no-env"].expect_success);
+    assert!(checks["no-env"].message.is_none());
+}
+
+#[test]
+fn test_save_keeps_comments_and_changes_only_edited_line() {
+    // resolve_conventions reads the cwd; keep it stable between load and save
+    let _guard = crate::test_lock::DIR_LOCK.lock().unwrap();
+    let original = r#"# Team manifest — edit with care
+version = 1
+
+[project]
+id = "test-project"
+version = "1.4.0" # bumped on release
+
+# Commands everyone runs
+[commands]
+dev = "pnpm dev"
+"#;
+    let mut tmp = tempfile::NamedTempFile::new().unwrap();
+    tmp.write_all(original.as_bytes()).unwrap();
+
+    let mut manifest = Manifest::load(tmp.path()).unwrap();
+    manifest.project.version = "1.5.0".to_string();
+    manifest.save(tmp.path()).unwrap();
+
+    let saved = std::fs::read_to_string(tmp.path()).unwrap();
+    assert_eq!(
+        saved,
+        original.replace(
+            "version = \"1.4.0\" # bumped on release",
+            "version = \"1.5.0\" # bumped on release"
+        )
+    );
+}
+
+#[test]
+fn test_save_errors_instead_of_rewriting() {
+    let broken = "# hand-edited\n[project\nid = \"x\"\n";
+    let mut tmp = tempfile::NamedTempFile::new().unwrap();
+    tmp.write_all(broken.as_bytes()).unwrap();
+
+    let manifest = Manifest::default_with_project("demo");
+    assert!(manifest.save(tmp.path()).is_err());
+    assert_eq!(std::fs::read_to_string(tmp.path()).unwrap(), broken);
+}
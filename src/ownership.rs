@@ -3,9 +3,27 @@
 //! Defines who "owns" each file type and how airis should handle them:
 //! - Tool: fully managed by airis, safe to backup and overwrite
 //! - User: never touch, owned by the user
+//!
+//! The built-in classification can be overridden per workspace in
+//! manifest.toml:
+//!
+//! ```toml
+//! [ownership]
+//! tool_owned = ["packages/api-client/src/generated/**"]
+//! user_owned = ["apps/legacy/**"]
+//! ```
+//!
+//! Precedence: `user_owned` globs, then `tool_owned` globs, then the defaults
+//! below. A path listed in both is user-owned, so a mistake errs on the side
+//! of not overwriting.
 
+use anyhow::{Context, Result};
+use glob::{MatchOptions, Pattern};
+use std::fs;
 use std::path::Path;
 
+use crate::manifest::{MANIFEST_FILE, OwnershipSection};
+
 /// Ownership level for a file
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Ownership {
@@ -15,8 +33,78 @@ pub enum Ownership {
     User,
 }
 
-/// Get the ownership level for a given file path
-pub fn get_ownership(path: &Path) -> Ownership {
+/// `*` stays within one path segment; use `**` to cross directories
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Compiled `[ownership]` overrides
+#[derive(Debug, Default)]
+pub struct OwnershipRules {
+    tool_owned: Vec<Pattern>,
+    user_owned: Vec<Pattern>,
+}
+
+impl OwnershipRules {
+    /// Compile the globs from a manifest's `[ownership]` section
+    pub fn from_section(section: &OwnershipSection) -> Result<Self> {
+        let compile = |globs: &[String], key: &str| -> Result<Vec<Pattern>> {
+            globs
+                .iter()
+                .map(|g| {
+                    Pattern::new(g.trim_start_matches("./"))
+                        .with_context(|| format!("Invalid glob in [ownership].{}: {}", key, g))
+                })
+                .collect()
+        };
+        Ok(Self {
+            tool_owned: compile(&section.tool_owned, "tool_owned")?,
+            user_owned: compile(&section.user_owned, "user_owned")?,
+        })
+    }
+
+    /// Read `[ownership]` from `root/manifest.toml`. Only that section is
+    /// parsed, so this works on manifests that would fail full validation.
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(MANIFEST_FILE);
+        let Ok(content) = fs::read_to_string(&path) else {
+            return Ok(Self::default());
+        };
+        let value: toml::Value = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        let section: OwnershipSection = match value.get("ownership") {
+            Some(table) => table
+                .clone()
+                .try_into()
+                .context("Invalid [ownership] section in manifest.toml")?,
+            None => return Ok(Self::default()),
+        };
+        Self::from_section(&section)
+    }
+
+    fn matches(patterns: &[Pattern], path: &str) -> bool {
+        patterns.iter().any(|p| p.matches_with(path, MATCH_OPTIONS))
+    }
+}
+
+/// Get the ownership level for a path relative to the workspace root,
+/// applying `[ownership]` overrides before the built-in defaults
+pub fn get_ownership(path: &Path, rules: &OwnershipRules) -> Ownership {
+    let path_str = path.to_string_lossy();
+    let path_str = path_str.trim_start_matches("./");
+    if OwnershipRules::matches(&rules.user_owned, path_str) {
+        return Ownership::User;
+    }
+    if OwnershipRules::matches(&rules.tool_owned, path_str) {
+        return Ownership::Tool;
+    }
+    default_ownership(path)
+}
+
+/// Built-in ownership for a path, ignoring manifest overrides
+pub fn default_ownership(path: &Path) -> Ownership {
     let path_str = path.to_string_lossy();
 
     // Exact matches first
@@ -110,37 +198,52 @@ mod tests {
 
     #[test]
     fn test_tool_owned_files() {
-        assert_eq!(get_ownership(Path::new("package.json")), Ownership::Tool);
-        assert_eq!(get_ownership(Path::new("tsconfig.json")), Ownership::Tool);
         assert_eq!(
-            get_ownership(Path::new("tsconfig.base.json")),
+            default_ownership(Path::new("package.json")),
+            Ownership::Tool
+        );
+        assert_eq!(
+            default_ownership(Path::new("tsconfig.json")),
             Ownership::Tool
         );
-        assert_eq!(get_ownership(Path::new("airis.lock")), Ownership::Tool);
         assert_eq!(
-            get_ownership(Path::new(".airis/internal-state.toml")),
+            default_ownership(Path::new("tsconfig.base.json")),
+            Ownership::Tool
+        );
+        assert_eq!(default_ownership(Path::new("airis.lock")), Ownership::Tool);
+        assert_eq!(
+            default_ownership(Path::new(".airis/internal-state.toml")),
             Ownership::Tool
         );
 
         // App package.json files are now tool-owned
         assert_eq!(
-            get_ownership(Path::new("apps/dashboard/package.json")),
+            default_ownership(Path::new("apps/dashboard/package.json")),
             Ownership::Tool
         );
     }
 
     #[test]
     fn test_user_owned_files() {
-        assert_eq!(get_ownership(Path::new("manifest.toml")), Ownership::User);
         assert_eq!(
-            get_ownership(Path::new("pnpm-workspace.yaml")),
+            default_ownership(Path::new("manifest.toml")),
+            Ownership::User
+        );
+        assert_eq!(
+            default_ownership(Path::new("pnpm-workspace.yaml")),
+            Ownership::User
+        );
+        assert_eq!(
+            default_ownership(Path::new("pnpm-lock.yaml")),
+            Ownership::User
+        );
+        assert_eq!(default_ownership(Path::new("Dockerfile")), Ownership::User);
+        assert_eq!(default_ownership(Path::new("compose.yml")), Ownership::User);
+        assert_eq!(
+            default_ownership(Path::new(".env.example")),
             Ownership::User
         );
-        assert_eq!(get_ownership(Path::new("pnpm-lock.yaml")), Ownership::User);
-        assert_eq!(get_ownership(Path::new("Dockerfile")), Ownership::User);
-        assert_eq!(get_ownership(Path::new("compose.yml")), Ownership::User);
-        assert_eq!(get_ownership(Path::new(".env.example")), Ownership::User);
-        assert_eq!(get_ownership(Path::new("CLAUDE.md")), Ownership::User);
+        assert_eq!(default_ownership(Path::new("CLAUDE.md")), Ownership::User);
     }
 
     #[test]
@@ -158,7 +261,7 @@ mod tests {
             "workspace/docker-compose.yml",
         ] {
             assert_eq!(
-                get_ownership(Path::new(name)),
+                default_ownership(Path::new(name)),
                 Ownership::User,
                 "{name} should be User-owned"
             );
@@ -167,6 +270,72 @@ mod tests {
 
     #[test]
     fn test_default_is_user() {
-        assert_eq!(get_ownership(Path::new("random-file.txt")), Ownership::User);
+        assert_eq!(
+            default_ownership(Path::new("random-file.txt")),
+            Ownership::User
+        );
+    }
+
+    fn overrides(tool_owned: &[&str], user_owned: &[&str]) -> OwnershipRules {
+        OwnershipRules::from_section(&OwnershipSection {
+            tool_owned: tool_owned.iter().map(|s| s.to_string()).collect(),
+            user_owned: user_owned.iter().map(|s| s.to_string()).collect(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_tool_owned_glob_overrides_default() {
+        let rules = overrides(&["libs/api/src/generated/**"], &[]);
+        assert_eq!(
+            get_ownership(Path::new("libs/api/src/generated/client.ts"), &rules),
+            Ownership::Tool
+        );
+        assert_eq!(
+            get_ownership(Path::new("libs/api/src/index.ts"), &rules),
+            Ownership::User
+        );
+    }
+
+    #[test]
+    fn test_user_owned_wins_over_tool_owned() {
+        let rules = overrides(&["apps/*/package.json"], &["apps/legacy/package.json"]);
+        assert_eq!(
+            get_ownership(Path::new("apps/legacy/package.json"), &rules),
+            Ownership::User
+        );
+        assert_eq!(
+            get_ownership(Path::new("apps/web/package.json"), &rules),
+            Ownership::Tool
+        );
+        // A user_owned glob also flips a default tool-owned file
+        let rules = overrides(&[], &["tsconfig.json"]);
+        assert_eq!(
+            get_ownership(Path::new("tsconfig.json"), &rules),
+            Ownership::User
+        );
+    }
+
+    #[test]
+    fn test_load_reads_ownership_section() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join(MANIFEST_FILE),
+            "version = 1\n[ownership]\ntool_owned = [\"docs/api.md\"]\n",
+        )
+        .unwrap();
+        let rules = OwnershipRules::load(dir.path()).unwrap();
+        assert_eq!(
+            get_ownership(Path::new("docs/api.md"), &rules),
+            Ownership::Tool
+        );
+
+        let empty = tempfile::tempdir().unwrap();
+        assert!(
+            OwnershipRules::load(empty.path())
+                .unwrap()
+                .user_owned
+                .is_empty()
+        );
     }
 }
@@ -35,7 +35,6 @@ pub struct Importer {
 /// A dependency entry
 #[derive(Debug, Deserialize)]
 pub struct Dependency {
-    #[allow(dead_code)]
     pub specifier: String,
     pub version: String,
 }
@@ -76,23 +75,69 @@ impl PnpmLock {
         let mut deps = Vec::new();
 
         // Check all dependency types
-        for dep in importer.dependencies.values() {
-            if let Some(path) = self.resolve_workspace_link(importer_path, &dep.version) {
+        let all = importer
+            .dependencies
+            .iter()
+            .chain(&importer.dev_dependencies)
+            .chain(&importer.peer_dependencies);
+        for (name, dep) in all {
+            if let Some(path) = self.resolve_workspace_dep(importer_path, name, dep)
+                && !deps.contains(&path)
+            {
                 deps.push(path);
             }
         }
-        for dep in importer.dev_dependencies.values() {
-            if let Some(path) = self.resolve_workspace_link(importer_path, &dep.version) {
-                deps.push(path);
-            }
+
+        deps
+    }
+
+    /// Resolve a dependency to a workspace package path, if it is one.
+    ///
+    /// pnpm normally records `workspace:` specifiers as `link:` versions. When
+    /// it doesn't (injected deps use `file:`, hand-edited or older lockfiles
+    /// keep the raw specifier), fall back to the `workspace:` specifier and
+    /// match the package (or its alias target) against importer paths.
+    fn resolve_workspace_dep(
+        &self,
+        importer_path: &str,
+        name: &str,
+        dep: &Dependency,
+    ) -> Option<String> {
+        if let Some(path) = self.resolve_workspace_link(importer_path, &dep.version) {
+            return Some(path);
         }
-        for dep in importer.peer_dependencies.values() {
-            if let Some(path) = self.resolve_workspace_link(importer_path, &dep.version) {
-                deps.push(path);
-            }
+
+        let spec = dep.specifier.strip_prefix("workspace:")?;
+
+        // Injected workspace deps: file:<path from workspace root>
+        if let Some(path) = dep.version.strip_prefix("file:")
+            && self.importers.contains_key(path)
+        {
+            return Some(path.to_string());
         }
 
-        deps
+        // Aliased: "alias": "workspace:@scope/ui@*" targets @scope/ui
+        let target = match spec.rfind('@') {
+            Some(i) if i > 0 => &spec[..i],
+            _ => name,
+        };
+        self.find_importer_by_name(target)
+    }
+
+    /// Find the importer path for a package name.
+    ///
+    /// Package names are matched on their unscoped part against the last
+    /// path segment, the same convention `build_workspace_map` uses for
+    /// names. Ambiguous matches resolve to `None`.
+    fn find_importer_by_name(&self, name: &str) -> Option<String> {
+        let short = name.rsplit('/').next().unwrap_or(name);
+        let mut matches = self
+            .importers
+            .keys()
+            .filter(|path| *path != "." && path.rsplit('/').next() == Some(short));
+
+        let first = matches.next()?;
+        matches.next().is_none().then(|| first.clone())
     }
 
     /// Resolve workspace link relative to importer path
@@ -219,4 +264,46 @@ mod tests {
         assert_eq!(lock.resolve_workspace_link("apps/foo", "1.2.3"), None);
         assert_eq!(lock.resolve_workspace_link("apps/foo", "workspace:*"), None);
     }
+
+    #[test]
+    fn test_build_workspace_map_resolves_workspace_protocol() {
+        let yaml = r#"
+lockfileVersion: '9.0'
+importers:
+  .: {}
+  apps/web:
+    dependencies:
+      '@scope/ui':
+        specifier: workspace:*
+        version: link:../../libs/ui
+      '@scope/config':
+        specifier: workspace:^
+        version: workspace:^
+      design:
+        specifier: workspace:@scope/tokens@*
+        version: workspace:@scope/tokens@*
+      react:
+        specifier: ^19.0.0
+        version: 19.0.0
+    devDependencies:
+      '@scope/testing':
+        specifier: workspace:~
+        version: file:libs/testing
+  libs/ui: {}
+  libs/config: {}
+  libs/tokens: {}
+  libs/testing: {}
+"#;
+        let lock: PnpmLock = serde_yaml_ng::from_str(yaml).unwrap();
+        let map = build_workspace_map(&lock);
+
+        let mut deps = map["apps/web"].workspace_deps.clone();
+        deps.sort();
+        assert_eq!(
+            deps,
+            vec!["libs/config", "libs/testing", "libs/tokens", "libs/ui"]
+        );
+        assert!(map["libs/ui"].workspace_deps.is_empty());
+        assert!(!map.contains_key("."));
+    }
 }
@@ -22,7 +22,7 @@ use std::path::{Path, PathBuf};
 use anyhow::{Context, Result, bail};
 use chrono::Local;
 
-use crate::ownership::{Ownership, get_ownership};
+use crate::ownership::{Ownership, OwnershipRules, get_ownership};
 
 /// Backup directory relative to workspace root
 const BACKUP_DIR: &str = ".airis/backups";
@@ -36,6 +36,8 @@ pub struct SafeOpResult {
     pub path: PathBuf,
     /// Backup path if a backup was created
     pub backup: Option<PathBuf>,
+    /// Bytes freed (or that would be freed, in dry-run) by `clean_artifact`
+    pub reclaimed: u64,
 }
 
 /// Type of action performed
@@ -45,6 +47,8 @@ pub enum SafeAction {
     Created,
     /// File was overwritten (backup created)
     Overwritten,
+    /// File already had the requested content (nothing written, no backup)
+    Unchanged,
     /// File was deleted (backup created)
     Deleted,
     /// File was skipped (user-owned or other reason)
@@ -61,6 +65,12 @@ pub struct SafeFS {
     root: PathBuf,
     /// Whether to actually perform operations or just preview
     dry_run: bool,
+    /// `[ownership]` overrides from the workspace manifest
+    ownership: OwnershipRules,
+    /// Whether `write` may overwrite user-owned files (still backed up)
+    force: bool,
+    /// Whether overwritten files are backed up first
+    backups: bool,
 }
 
 impl SafeFS {
@@ -88,7 +98,28 @@ impl SafeFS {
             );
         }
 
-        Ok(Self { root, dry_run })
+        let ownership = OwnershipRules::load(&root)?;
+
+        Ok(Self {
+            root,
+            dry_run,
+            ownership,
+            force: false,
+            backups: true,
+        })
+    }
+
+    /// Let `write` overwrite user-owned files. The previous content is still
+    /// backed up, so an intentional regeneration stays recoverable.
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Turn backups of overwritten files on or off (on by default)
+    pub fn with_backups(mut self, backups: bool) -> Self {
+        self.backups = backups;
+        self
     }
 
     /// Create a SafeFS for the current directory
@@ -123,33 +154,38 @@ impl SafeFS {
             self.root.join(path)
         };
 
-        // For existing paths, canonicalize to resolve symlinks
-        // For non-existing paths, canonicalize parent and append filename
+        // For existing paths, canonicalize to resolve symlinks.
+        // For new paths, canonicalize the nearest existing ancestor (so a
+        // symlinked directory anywhere above is resolved) and append the
+        // missing components, which must be plain names.
         let canonical = if full_path.exists() {
             full_path.canonicalize()?
         } else {
-            // Path doesn't exist yet - canonicalize parent
-            let parent = full_path
-                .parent()
-                .ok_or_else(|| anyhow::anyhow!("Invalid path: no parent"))?;
-
-            if parent.exists() {
-                let canonical_parent = parent.canonicalize()?;
-                let filename = full_path
-                    .file_name()
-                    .ok_or_else(|| anyhow::anyhow!("Invalid path: no filename"))?;
-                canonical_parent.join(filename)
-            } else {
-                // Parent doesn't exist either - this is fine for new paths
-                // Just ensure we don't have any .. in the path
-                if path.to_string_lossy().contains("..") {
+            let mut ancestor = full_path.as_path();
+            let mut missing = Vec::new();
+            while !ancestor.exists() {
+                // exists() follows links: a dangling symlink could point anywhere
+                if ancestor.symlink_metadata().is_ok() {
                     bail!(
-                        "Path traversal rejected: {} (contains '..')",
-                        path.display()
+                        "Operation rejected: '{}' is a dangling symlink",
+                        ancestor.display()
                     );
                 }
-                full_path
+                let name = ancestor.file_name().ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Path traversal rejected: {} (contains '..')",
+                        path.display()
+                    )
+                })?;
+                missing.push(name);
+                ancestor = ancestor
+                    .parent()
+                    .ok_or_else(|| anyhow::anyhow!("Invalid path: no parent"))?;
             }
+            missing
+                .iter()
+                .rev()
+                .fold(ancestor.canonicalize()?, |acc, name| acc.join(name))
         };
 
         // CRITICAL: Ensure path is within workspace root
@@ -211,18 +247,18 @@ impl SafeFS {
     /// # Behavior
     /// - Creates parent directories if needed
     /// - Creates backup if file exists and will be overwritten
-    /// - Respects ownership rules
+    /// - Respects ownership rules (user-owned files are skipped unless `force`)
     /// - In dry-run mode, only reports what would happen
     pub fn write(&self, path: impl AsRef<Path>, content: impl AsRef<[u8]>) -> Result<SafeOpResult> {
         let path = self.resolve_and_validate(path)?;
         let relative_path = path.strip_prefix(&self.root).unwrap_or(&path);
-        let ownership = get_ownership(relative_path);
+        let ownership = get_ownership(relative_path, &self.ownership);
 
         // Check if file exists
         let exists = path.exists();
 
         // Check ownership rules
-        if exists && ownership == Ownership::User {
+        if exists && ownership == Ownership::User && !self.force {
             return Ok(SafeOpResult {
                 action: SafeAction::Skipped(format!(
                     "User-owned file (use --force to override): {}",
@@ -230,6 +266,39 @@ impl SafeFS {
                 )),
                 path,
                 backup: None,
+                reclaimed: 0,
+            });
+        }
+
+        self.write_resolved(path, content.as_ref())
+    }
+
+    /// Apply an explicit, user-requested edit to a file regardless of ownership
+    ///
+    /// Unlike [`SafeFS::write`], user-owned files are not skipped: this is for
+    /// commands that edit a user file on the user's behalf (e.g. `airis new`
+    /// registering a project in manifest.toml). A backup is still created.
+    pub fn edit(&self, path: impl AsRef<Path>, content: impl AsRef<[u8]>) -> Result<SafeOpResult> {
+        let path = self.resolve_and_validate(path)?;
+        self.write_resolved(path, content.as_ref())
+    }
+
+    /// Whether `path` (relative to the workspace root) already holds exactly `content`
+    pub fn is_unchanged(&self, path: impl AsRef<Path>, content: impl AsRef<[u8]>) -> bool {
+        fs::read(self.root.join(path)).is_ok_and(|existing| existing == content.as_ref())
+    }
+
+    /// Write to an already-validated path: dry-run check, backup, then write.
+    /// Identical content is left alone so re-runs cause no churn or backups.
+    fn write_resolved(&self, path: PathBuf, content: &[u8]) -> Result<SafeOpResult> {
+        let exists = path.exists();
+
+        if exists && self.is_unchanged(&path, content) {
+            return Ok(SafeOpResult {
+                action: SafeAction::Unchanged,
+                path,
+                backup: None,
+                reclaimed: 0,
             });
         }
 
@@ -242,6 +311,7 @@ impl SafeFS {
                 },
                 path,
                 backup: None,
+                reclaimed: 0,
             });
         }
 
@@ -252,7 +322,7 @@ impl SafeFS {
         }
 
         // Create backup if overwriting
-        let backup = if exists {
+        let backup = if exists && self.backups {
             Some(self.create_backup(&path)?)
         } else {
             None
@@ -270,6 +340,7 @@ impl SafeFS {
             },
             path,
             backup,
+            reclaimed: 0,
         })
     }
 
@@ -282,7 +353,7 @@ impl SafeFS {
     pub fn delete(&self, path: impl AsRef<Path>) -> Result<SafeOpResult> {
         let path = self.resolve_and_validate(path)?;
         let relative_path = path.strip_prefix(&self.root).unwrap_or(&path);
-        let ownership = get_ownership(relative_path);
+        let ownership = get_ownership(relative_path, &self.ownership);
 
         // Check if path exists
         if !path.exists() {
@@ -290,6 +361,7 @@ impl SafeFS {
                 action: SafeAction::Skipped("File does not exist".to_string()),
                 path,
                 backup: None,
+                reclaimed: 0,
             });
         }
 
@@ -302,6 +374,7 @@ impl SafeFS {
                 )),
                 path,
                 backup: None,
+                reclaimed: 0,
             });
         }
 
@@ -310,6 +383,7 @@ impl SafeFS {
                 action: SafeAction::WouldDelete,
                 path,
                 backup: None,
+                reclaimed: 0,
             });
         }
 
@@ -329,6 +403,7 @@ impl SafeFS {
             action: SafeAction::Deleted,
             path,
             backup: Some(backup),
+            reclaimed: 0,
         })
     }
 
@@ -345,6 +420,7 @@ impl SafeFS {
                 action: SafeAction::Skipped("Does not exist".to_string()),
                 path,
                 backup: None,
+                reclaimed: 0,
             });
         }
 
@@ -396,16 +472,20 @@ impl SafeFS {
                         action: SafeAction::Skipped(format!("Protected path: {}", relative_str)),
                         path,
                         backup: None,
+                        reclaimed: 0,
                     });
                 }
             }
         }
 
+        let reclaimed = disk_usage(&path);
+
         if self.dry_run {
             return Ok(SafeOpResult {
                 action: SafeAction::WouldDelete,
                 path,
                 backup: None,
+                reclaimed,
             });
         }
 
@@ -422,6 +502,7 @@ impl SafeFS {
             action: SafeAction::Deleted,
             path,
             backup: None,
+            reclaimed,
         })
     }
 
@@ -480,6 +561,18 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Total size in bytes of a file or directory tree, without following symlinks.
+/// Unreadable entries count as zero.
+fn disk_usage(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.metadata().ok())
+        .filter(|meta| meta.is_file())
+        .map(|meta| meta.len())
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -586,6 +679,123 @@ mod tests {
         assert!(workspace.path().join("manifest.toml").exists());
     }
 
+    #[test]
+    fn test_safefs_write_honors_ownership_overrides() {
+        let workspace = create_test_workspace();
+        fs::write(
+            workspace.path().join("manifest.toml"),
+            "version = 1\n[project]\nid = \"test\"\n\n[ownership]\ntool_owned = [\"docs/generated/**\"]\nuser_owned = [\"tsconfig.json\"]\n",
+        )
+        .unwrap();
+        fs::create_dir_all(workspace.path().join("docs/generated")).unwrap();
+        fs::write(workspace.path().join("docs/generated/api.md"), "old").unwrap();
+        fs::write(workspace.path().join("tsconfig.json"), "{}").unwrap();
+
+        let safe_fs = SafeFS::new(workspace.path(), false).unwrap();
+
+        // Defaults to user-owned, flipped to tool-owned by the glob
+        let result = safe_fs.write("docs/generated/api.md", "new").unwrap();
+        assert_eq!(result.action, SafeAction::Overwritten);
+        assert!(result.backup.is_some());
+
+        // Defaults to tool-owned, flipped to user-owned
+        let result = safe_fs.write("tsconfig.json", "{\"x\": 1}").unwrap();
+        assert!(matches!(result.action, SafeAction::Skipped(_)));
+        assert_eq!(
+            fs::read_to_string(workspace.path().join("tsconfig.json")).unwrap(),
+            "{}"
+        );
+    }
+
+    #[test]
+    fn test_safefs_force_overwrites_user_owned_with_backup() {
+        let workspace = create_test_workspace();
+        fs::write(workspace.path().join("Dockerfile"), "FROM custom").unwrap();
+
+        let safe_fs = SafeFS::new(workspace.path(), false).unwrap();
+        let result = safe_fs.write("Dockerfile", "FROM node:22").unwrap();
+        assert!(matches!(result.action, SafeAction::Skipped(_)));
+
+        let safe_fs = safe_fs.with_force(true);
+        let result = safe_fs.write("Dockerfile", "FROM node:22").unwrap();
+        assert_eq!(result.action, SafeAction::Overwritten);
+        assert_eq!(
+            fs::read_to_string(result.backup.unwrap()).unwrap(),
+            "FROM custom"
+        );
+        assert_eq!(
+            fs::read_to_string(workspace.path().join("Dockerfile")).unwrap(),
+            "FROM node:22"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_safefs_rejects_new_files_through_symlinked_dir() {
+        let workspace = create_test_workspace();
+        let outside = tempdir().unwrap();
+        std::os::unix::fs::symlink(outside.path(), workspace.path().join("escape")).unwrap();
+        let safe_fs = SafeFS::new(workspace.path(), false).unwrap();
+
+        // Parent exists (the symlink itself)
+        assert!(safe_fs.write("escape/new.txt", "x").is_err());
+        // Parent missing: the symlink is further up
+        assert!(safe_fs.write("escape/nested/dir/new.txt", "x").is_err());
+        assert!(!outside.path().join("new.txt").exists());
+        assert!(!outside.path().join("nested").exists());
+
+        // Dangling symlink pointing outside
+        std::os::unix::fs::symlink(
+            outside.path().join("missing.txt"),
+            workspace.path().join("dangling.txt"),
+        )
+        .unwrap();
+        assert!(safe_fs.write("dangling.txt", "x").is_err());
+        assert!(!outside.path().join("missing.txt").exists());
+
+        // Regular new nested paths still work
+        assert!(safe_fs.write("apps/web/new.txt", "x").is_ok());
+    }
+
+    #[test]
+    fn test_safefs_rewrite_with_same_content_is_unchanged() {
+        let workspace = create_test_workspace();
+        let safe_fs = SafeFS::new(workspace.path(), false).unwrap();
+
+        let first = safe_fs.write("tsconfig.json", "{}\n").unwrap();
+        assert_eq!(first.action, SafeAction::Created);
+        let backups_before = safe_fs.list_backups().unwrap().len();
+
+        let second = safe_fs.write("tsconfig.json", "{}\n").unwrap();
+        assert_eq!(second.action, SafeAction::Unchanged);
+        assert!(second.backup.is_none());
+        assert_eq!(safe_fs.list_backups().unwrap().len(), backups_before);
+
+        let dry = SafeFS::new(workspace.path(), true).unwrap();
+        let result = dry.write("tsconfig.json", "{}\n").unwrap();
+        assert_eq!(result.action, SafeAction::Unchanged);
+    }
+
+    #[test]
+    fn test_safefs_edit_backs_up_user_owned_file() {
+        let workspace = create_test_workspace();
+        let safe_fs = SafeFS::new(workspace.path(), false).unwrap();
+
+        // write() skips manifest.toml (user-owned)...
+        let result = safe_fs.write("manifest.toml", "ignored").unwrap();
+        assert!(matches!(result.action, SafeAction::Skipped(_)));
+
+        // ...but an explicit edit goes through and keeps a backup
+        let result = safe_fs.edit("manifest.toml", "edited").unwrap();
+        assert!(matches!(result.action, SafeAction::Overwritten));
+        let backup = result.backup.unwrap();
+        assert!(fs::read_to_string(backup).unwrap().contains("[project]"));
+        assert_eq!(
+            fs::read_to_string(workspace.path().join("manifest.toml")).unwrap(),
+            "edited"
+        );
+    }
+
     #[test]
     fn test_safefs_clean_artifact_protects_critical() {
         let workspace = create_test_workspace();
@@ -626,6 +836,25 @@ mod tests {
         assert!(matches!(result.action, SafeAction::Deleted));
     }
 
+    #[test]
+    fn test_safefs_clean_artifact_reports_reclaimed_bytes() {
+        let workspace = create_test_workspace();
+        fs::create_dir_all(workspace.path().join("dist/assets")).unwrap();
+        fs::write(workspace.path().join("dist/index.js"), vec![0u8; 1000]).unwrap();
+        fs::write(workspace.path().join("dist/assets/app.css"), vec![0u8; 24]).unwrap();
+
+        let dry = SafeFS::new(workspace.path(), true).unwrap();
+        let result = dry.clean_artifact("dist").unwrap();
+        assert_eq!(result.action, SafeAction::WouldDelete);
+        assert_eq!(result.reclaimed, 1024);
+
+        let safe_fs = SafeFS::new(workspace.path(), false).unwrap();
+        let result = safe_fs.clean_artifact("dist").unwrap();
+        assert_eq!(result.action, SafeAction::Deleted);
+        assert_eq!(result.reclaimed, 1024);
+        assert!(!workspace.path().join("dist").exists());
+    }
+
     #[test]
     fn test_safefs_clean_artifact_allows_node_modules_in_protected_paths() {
         let workspace = create_test_workspace();
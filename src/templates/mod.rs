@@ -2,6 +2,8 @@ mod package;
 mod tsconfig;
 
 use anyhow::Result;
+use indexmap::IndexMap;
+use regex::Regex;
 use serde_json::json;
 
 pub struct TemplateEngine;
@@ -10,6 +12,20 @@ impl TemplateEngine {
     pub fn new() -> Result<Self> {
         Ok(TemplateEngine)
     }
+
+    /// Render `{{var}}` placeholders in a user-supplied template.
+    ///
+    /// Unknown variables are left untouched so templates for other tools
+    /// (e.g. GitHub Actions `${{ ... }}` expressions) pass through.
+    pub fn render_str(&self, template: &str, vars: &IndexMap<String, String>) -> String {
+        let re = Regex::new(r"\{\{\s*([A-Za-z_][A-Za-z0-9_]*)\s*\}\}").expect("valid regex");
+        re.replace_all(template, |caps: &regex::Captures| {
+            vars.get(&caps[1])
+                .cloned()
+                .unwrap_or_else(|| caps[0].to_string())
+        })
+        .into_owned()
+    }
 }
 
 /// Convert a TOML value to a serde_json value for tsconfig generation.
@@ -66,18 +66,10 @@ impl TemplateEngine {
             }
         }
         if !merged_deps.is_empty() {
-            let mut deps = serde_json::Map::new();
-            for (k, v) in &merged_deps {
-                // If it's in the manifest catalog, use "catalog:"
-                let version =
-                    if resolved_catalog.contains_key(k) || v == "catalog" || v == "catalog:" {
-                        "catalog:".to_string()
-                    } else {
-                        v.clone()
-                    };
-                deps.insert(k.clone(), serde_json::json!(version));
-            }
-            obj.insert("dependencies".to_string(), serde_json::Value::Object(deps));
+            obj.insert(
+                "dependencies".to_string(),
+                render_dependencies(&merged_deps, resolved_catalog),
+            );
         }
 
         // Merge devDependencies: packages.root first, then [root] overrides.
@@ -88,19 +80,16 @@ impl TemplateEngine {
             }
         }
         if !merged_dev_deps.is_empty() {
-            let mut dev_deps = serde_json::Map::new();
-            for (k, v) in &merged_dev_deps {
-                let version =
-                    if resolved_catalog.contains_key(k) || v == "catalog" || v == "catalog:" {
-                        "catalog:".to_string()
-                    } else {
-                        v.clone()
-                    };
-                dev_deps.insert(k.clone(), serde_json::json!(version));
-            }
             obj.insert(
                 "devDependencies".to_string(),
-                serde_json::Value::Object(dev_deps),
+                render_dependencies(&merged_dev_deps, resolved_catalog),
+            );
+        }
+
+        if !root_pkg.optional_dependencies.is_empty() {
+            obj.insert(
+                "optionalDependencies".to_string(),
+                render_dependencies(&root_pkg.optional_dependencies, resolved_catalog),
             );
         }
 
@@ -152,3 +141,27 @@ impl TemplateEngine {
         Ok(format!("{content}\n"))
     }
 }
+
+/// Render a dependency map for package.json.
+///
+/// Entries are sorted by package name so output doesn't depend on the order
+/// the manifest or catalog resolution inserted them. Catalog-managed packages
+/// are emitted as `"catalog:"`.
+fn render_dependencies(
+    deps: &IndexMap<String, String>,
+    resolved_catalog: &IndexMap<String, String>,
+) -> serde_json::Value {
+    let mut sorted: Vec<(&String, &String)> = deps.iter().collect();
+    sorted.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut out = serde_json::Map::new();
+    for (k, v) in sorted {
+        let version = if resolved_catalog.contains_key(k) || v == "catalog" || v == "catalog:" {
+            "catalog:".to_string()
+        } else {
+            v.clone()
+        };
+        out.insert(k.clone(), serde_json::json!(version));
+    }
+    serde_json::Value::Object(out)
+}
@@ -373,3 +373,64 @@ test = "vitest run"
         "test script must survive airis gen"
     );
 }
+
+#[test]
+fn test_render_str_substitutes_known_vars_only() {
+    let engine = TemplateEngine::new().unwrap();
+    let mut vars = IndexMap::new();
+    vars.insert("name".to_string(), "billing".to_string());
+
+    let out = engine.render_str(
+        "{\"name\": \"@org/{{name}}\", \"x\": \"{{ name }}\", \"ci\": \"${{ github.sha }}\", \"y\": \"{{other}}\"}",
+        &vars,
+    );
+
+    assert_eq!(
+        out,
+        "{\"name\": \"@org/billing\", \"x\": \"billing\", \"ci\": \"${{ github.sha }}\", \"y\": \"{{other}}\"}"
+    );
+}
+
+#[test]
+fn test_render_package_json_sorts_dependencies_deterministically() {
+    let toml_str = r#"
+version = 1
+[project]
+id = "test"
+
+[workspace]
+name = "my-workspace"
+
+[packages.root.scripts]
+test = "vitest run"
+build = "turbo build"
+
+[packages.root.dependencies]
+zod = "^3.0.0"
+"@hono/node-server" = "^1.0.0"
+
+[root.devDependencies]
+vitest = "catalog:"
+eslint = "^9.0.0"
+"@types/node" = "^22.0.0"
+"#;
+    let manifest: Manifest = toml::from_str(toml_str).unwrap();
+    let engine = TemplateEngine::new().unwrap();
+    let mut catalog = IndexMap::new();
+    catalog.insert("zod".to_string(), "^3.0.0".to_string());
+
+    let first = engine.render_package_json(&manifest, &catalog).unwrap();
+    let second = engine.render_package_json(&manifest, &catalog).unwrap();
+    assert_eq!(first, second, "rendering must be byte-identical");
+
+    let json: serde_json::Value = serde_json::from_str(&first).unwrap();
+    let keys =
+        |field: &str| -> Vec<String> { json[field].as_object().unwrap().keys().cloned().collect() };
+    assert_eq!(keys("dependencies"), vec!["@hono/node-server", "zod"]);
+    assert_eq!(
+        keys("devDependencies"),
+        vec!["@types/node", "eslint", "vitest"]
+    );
+    // Scripts keep their authored order
+    assert_eq!(keys("scripts"), vec!["test", "build"]);
+}
@@ -51,3 +51,50 @@ fn test_policy_check_no_config() {
     // Should succeed with default config (no policies.toml)
     airis().args(["policy", "check"]).assert().success();
 }
+
+#[test]
+fn test_missing_manifest_exit_code() {
+    let dir = tempfile::tempdir().unwrap();
+    airis()
+        .current_dir(dir.path())
+        .args(["validate", "manifest"])
+        .assert()
+        .code(3)
+        .stderr(predicate::str::contains("manifest.toml not found"));
+}
+
+#[test]
+fn test_manifest_get_without_manifest_exits_3() {
+    let dir = tempfile::tempdir().unwrap();
+    airis()
+        .current_dir(dir.path())
+        .args(["manifest", "get", "x"])
+        .assert()
+        .code(3)
+        .stderr(predicate::str::contains("manifest.toml not found"));
+}
+
+#[test]
+fn test_clean_keeps_dir_holding_airisignore_path() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("manifest.toml"),
+        "version = 1\n\n[project]\nid = \"demo\"\n",
+    )
+    .unwrap();
+    std::fs::write(dir.path().join(".airisignore"), "dist/keep.me\n").unwrap();
+    std::fs::create_dir(dir.path().join("dist")).unwrap();
+    std::fs::write(dir.path().join("dist/keep.me"), "keep").unwrap();
+    std::fs::write(dir.path().join("dist/app.js"), "").unwrap();
+
+    airis()
+        .current_dir(dir.path())
+        .args(["clean", "--force"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "contains paths kept by .airisignore",
+        ));
+
+    assert!(dir.path().join("dist/keep.me").exists());
+}